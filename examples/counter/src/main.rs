@@ -1,6 +1,7 @@
 use concoct::{
     view::{self, View},
     web::{Html, Web},
+    Sub,
 };
 
 enum Event {
@@ -11,16 +12,8 @@ enum Event {
 fn counter(count: &i32) -> impl View<Web<Event>> {
     (
         Html::h1().view(count.to_string()),
-        view::once(
-            Html::button()
-                .on("click", |_| Event::Increment)
-                .view("More"),
-        ),
-        view::once(
-            Html::button()
-                .on("click", |_| Event::Decrement)
-                .view("Less"),
-        ),
+        view::once(Html::button().on_click(|_| Event::Increment).view("More")),
+        view::once(Html::button().on_click(|_| Event::Decrement).view("Less")),
     )
 }
 
@@ -32,5 +25,6 @@ fn main() {
             Event::Decrement => *count -= 1,
         },
         counter,
+        |_| Sub::none(),
     );
 }
@@ -1,6 +1,7 @@
 use concoct::{
     native::{view::text, Native},
     view::View,
+    Sub,
 };
 
 enum Event {
@@ -20,5 +21,6 @@ fn main() {
             Event::Decrement => *count -= 1,
         },
         counter,
+        |_| Sub::none(),
     );
 }
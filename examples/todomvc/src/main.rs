@@ -1,7 +1,7 @@
 use concoct::{
     view::{lazy, once, View},
-    web::{on, ClassList, Element, EventExt, Html, Web},
-    Modify,
+    web::{on, Element, EventExt, Html, Web},
+    Modify, Sub,
 };
 use std::mem;
 
@@ -62,9 +62,9 @@ fn view_input(input: String) -> impl View<Web<Event>> {
             .attr("autofocus", "True")
             .attr("name", "newTodo")
             .value(input)
-            .on("input", |event| {
+            .on_input(|event| {
                 event.prevent_default();
-                Event::UpdateInput(event.target_value())
+                Event::UpdateInput(event.target_value().unwrap_or_default())
             })
             .modify(on_enter(|| Event::Add)),
     ))
@@ -75,7 +75,7 @@ fn view_entries(state: &Model) -> impl View<Web<Event>> {
         state
             .todos
             .iter()
-            .map(|todo| (todo.id, view_entry(&todo)))
+            .map(|todo| (todo.id, view_entry(todo)))
             .collect::<Vec<_>>(),
     )
 }
@@ -83,42 +83,37 @@ fn view_entries(state: &Model) -> impl View<Web<Event>> {
 fn view_entry(todo: &Todo) -> impl View<Web<Event>> {
     let id = todo.id;
 
-    let mut class_list = ClassList::default();
-    if todo.is_completed {
-        class_list.class("completed");
-    }
-    if todo.is_editing {
-        class_list.class("editing");
-    }
-
-    Html::li().class(class_list).view((
-        Html::div().class("view").view((
+    Html::li()
+        .class_if("completed", todo.is_completed)
+        .class_if("editing", todo.is_editing)
+        .view((
+            Html::div().class("view").view((
+                Html::input()
+                    .class("toggle")
+                    .attr("type", "checkbox")
+                    .prop("checked", todo.is_completed)
+                    .on_click(move |_| Event::Check(id)),
+                Html::label()
+                    .on_click(move |_| Event::edit(id, true))
+                    .view(todo.content.clone()),
+                Html::button()
+                    .class("destroy")
+                    .on_click(move |_| Event::Remove(id)),
+            )),
             Html::input()
-                .class("toggle")
-                .attr("type", "checkbox")
-                .attr("checked", todo.is_completed.to_string())
-                .on("click", move |_| Event::Check(id)),
-            Html::label()
-                .on("click", move |_| Event::edit(id, true))
-                .view(todo.content.clone()),
-            Html::button()
-                .class("destroy")
-                .on("click", move |_| Event::Remove(id)),
-        )),
-        Html::input()
-            .class("edit")
-            .value(todo.content.clone())
-            .attr("name", "content")
-            .on("input", move |event| {
-                event.prevent_default();
-                Event::Update {
-                    id,
-                    content: event.target_value(),
-                }
-            })
-            .on("blur", move |_| Event::edit(id, false))
-            .modify(on_enter(move || Event::edit(id, false))),
-    ))
+                .class("edit")
+                .value(todo.content.clone())
+                .attr("name", "content")
+                .on_input(move |event| {
+                    event.prevent_default();
+                    Event::Update {
+                        id,
+                        content: event.target_value().unwrap_or_default(),
+                    }
+                })
+                .on_blur(move |_| Event::edit(id, false))
+                .modify(on_enter(move || Event::edit(id, false))),
+        ))
 }
 
 fn view_footer() -> impl View<Web<Event>> {
@@ -129,7 +124,7 @@ fn view_footer() -> impl View<Web<Event>> {
 
 fn on_enter(f: impl Fn() -> Event + 'static) -> impl Modify<Web<Event>, Element> {
     on("keydown", move |event| {
-        if event.key_code() == 13 {
+        if event.key_code() == Some(13) {
             f()
         } else {
             Event::None
@@ -179,5 +174,6 @@ fn main() {
             }
         },
         view,
-    )
+        |_| Sub::none(),
+    );
 }
@@ -0,0 +1,95 @@
+use std::rc::Rc;
+
+type Effect<E> = Box<dyn FnOnce(Rc<dyn Fn(E)>)>;
+
+/// Side effect to perform after an update.
+///
+/// Commands are returned from the `update` function passed to a platform's `run`.
+/// The runtime runs each effect with a function to send messages back to `update`.
+///
+/// Update functions that return `()` produce [`Command::none`].
+pub struct Command<E> {
+    effects: Vec<Effect<E>>,
+}
+
+impl<E> Command<E> {
+    /// Command that does nothing.
+    pub fn none() -> Self {
+        Self {
+            effects: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if this command has no effects.
+    pub fn is_none(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Command that runs `f` with a function to send messages.
+    ///
+    /// The send function can be stored and called later, for example from a timer or
+    /// request callback.
+    pub fn effect(f: impl FnOnce(Rc<dyn Fn(E)>) + 'static) -> Self {
+        Self {
+            effects: vec![Box::new(f)],
+        }
+    }
+
+    /// Command that sends a message.
+    pub fn message(msg: E) -> Self
+    where
+        E: 'static,
+    {
+        Self::effect(move |send| send(msg))
+    }
+
+    /// Combine multiple commands into one.
+    pub fn batch(commands: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            effects: commands
+                .into_iter()
+                .flat_map(|command| command.effects)
+                .collect(),
+        }
+    }
+
+    /// Map the messages of this command to another type.
+    pub fn map<E2>(self, f: impl Fn(E) -> E2 + 'static) -> Command<E2>
+    where
+        E: 'static,
+        E2: 'static,
+    {
+        let f = Rc::new(f);
+        Command {
+            effects: self
+                .effects
+                .into_iter()
+                .map(|effect| {
+                    let f = f.clone();
+                    Box::new(move |send: Rc<dyn Fn(E2)>| {
+                        effect(Rc::new(move |msg| send(f(msg))))
+                    }) as Effect<E2>
+                })
+                .collect(),
+        }
+    }
+
+    /// Run the effects of this command, sending their messages to `send`.
+    pub fn run(self, send: Rc<dyn Fn(E)>) {
+        for effect in self.effects {
+            effect(send.clone())
+        }
+    }
+}
+
+impl<E> Default for Command<E> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<E> From<()> for Command<E> {
+    fn from(_: ()) -> Self {
+        Self::none()
+    }
+}
@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+/// Value of an attribute, where `None` removes the attribute.
+pub trait AttrValue {
+    fn into_attr_value(self) -> Option<Cow<'static, str>>;
+}
+
+impl AttrValue for Cow<'static, str> {
+    fn into_attr_value(self) -> Option<Cow<'static, str>> {
+        Some(self)
+    }
+}
+
+impl AttrValue for &'static str {
+    fn into_attr_value(self) -> Option<Cow<'static, str>> {
+        Some(self.into())
+    }
+}
+
+impl AttrValue for String {
+    fn into_attr_value(self) -> Option<Cow<'static, str>> {
+        Some(self.into())
+    }
+}
+
+/// Boolean attributes are present with an empty value when `true` and removed when `false`.
+impl AttrValue for bool {
+    fn into_attr_value(self) -> Option<Cow<'static, str>> {
+        self.then_some(Cow::Borrowed(""))
+    }
+}
+
+impl<T: AttrValue> AttrValue for Option<T> {
+    fn into_attr_value(self) -> Option<Cow<'static, str>> {
+        self.and_then(AttrValue::into_attr_value)
+    }
+}
+
+/// Set the class attribute for an element.
+pub fn class(value: impl Into<Cow<'static, str>>) -> Attr {
+    attr("class", value.into())
+}
+
+/// Set the boolean `disabled` attribute for an element.
+pub fn disabled(is_disabled: bool) -> Attr {
+    attr("disabled", is_disabled)
+}
+
+/// Set the boolean `checked` attribute for an element.
+///
+/// This sets whether an input is checked by default. Use [`prop`](crate::web::prop) with
+/// `"checked"` to keep it in sync once the user has changed it.
+pub fn checked(is_checked: bool) -> Attr {
+    attr("checked", is_checked)
+}
+
+/// Set the boolean `hidden` attribute for an element.
+pub fn hidden(is_hidden: bool) -> Attr {
+    attr("hidden", is_hidden)
+}
+
+/// Set a stringly-typed attribute for an element.
+///
+/// The attribute is removed if `value` is `None` or `false`.
+pub fn attr(name: impl Into<Cow<'static, str>>, value: impl AttrValue) -> Attr {
+    Attr {
+        name: name.into(),
+        value: value.into_attr_value(),
+    }
+}
+
+/// View for the [`attr`] function.
+pub struct Attr {
+    pub(crate) name: Cow<'static, str>,
+    pub(crate) value: Option<Cow<'static, str>>,
+}
@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+/// Add a list of classes to an element.
+///
+/// Unlike setting the `class` attribute, this only adds and removes the classes that changed
+/// through `Element.classList`, so classes added by other scripts are kept.
+pub fn classes<I>(classes: I) -> Classes
+where
+    I: IntoIterator,
+    I::Item: Into<Cow<'static, str>>,
+{
+    Classes {
+        classes: classes.into_iter().map(Into::into).collect(),
+    }
+}
+
+/// Modifier for the [`classes`] function.
+pub struct Classes {
+    pub(crate) classes: Vec<Cow<'static, str>>,
+}
+
+/// Add a class to an element if `condition` is `true`, otherwise remove it.
+pub fn class_if(name: impl Into<Cow<'static, str>>, condition: bool) -> ClassIf {
+    ClassIf {
+        name: name.into(),
+        condition,
+    }
+}
+
+/// Modifier for the [`class_if`] function.
+pub struct ClassIf {
+    pub(crate) name: Cow<'static, str>,
+    pub(crate) condition: bool,
+}
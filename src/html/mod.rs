@@ -0,0 +1,298 @@
+//! Html views and modifiers.
+//!
+//! These are shared by the [`Web`](crate::web::Web) and [`Ssr`](crate::ssr::Ssr) platforms,
+//! so the same views can be built in the browser and rendered to a string on a server.
+
+use std::{borrow::Cow, marker::PhantomData};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, FocusEvent, KeyboardEvent, MouseEvent};
+
+mod attr;
+pub use attr::{attr, checked, class, disabled, hidden, Attr, AttrValue};
+
+mod classes;
+pub use classes::{class_if, classes, ClassIf, Classes};
+
+mod namespace;
+pub use namespace::{MathMl, Svg, MATHML_NAMESPACE, SVG_NAMESPACE};
+
+mod on;
+pub use on::{on, on_event, Handler, On, OnEvent, Typed};
+
+mod style;
+pub use style::{style, CssProperty, Length, Style, StyleValue};
+
+mod value;
+pub use value::{value, Value};
+
+/// String of class names for the `class` attribute.
+///
+/// See [`classes`] and [`class_if`] for modifiers that
+/// only update the classes that changed.
+#[derive(Clone, Debug, Default)]
+pub struct ClassList {
+    string: String,
+    has_class: bool,
+}
+
+impl ClassList {
+    pub fn class(&mut self, class_name: impl AsRef<str>) -> &mut Self {
+        if self.has_class {
+            self.string.push(' ');
+        }
+        self.has_class = true;
+        self.string.push_str(class_name.as_ref());
+
+        self
+    }
+
+    pub fn build(&self) -> String {
+        self.string.clone()
+    }
+}
+
+impl From<ClassList> for Cow<'static, str> {
+    fn from(value: ClassList) -> Self {
+        value.string.into()
+    }
+}
+
+/// Html element view.
+///
+/// Elements without a namespace inherit the namespace of their parent,
+/// so children of an [`Svg`] element are also created as SVG.
+pub struct Html<A, V, E> {
+    pub(crate) tag: Cow<'static, str>,
+    pub(crate) namespace: Option<Cow<'static, str>>,
+    pub(crate) modify: A,
+    pub(crate) view: V,
+    _marker: PhantomData<E>,
+}
+
+impl<E> Html<(), (), E> {
+    pub fn new(tag: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            tag: tag.into(),
+            namespace: None,
+            modify: (),
+            view: (),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create an element in the namespace with the URI `namespace`.
+    pub fn new_ns(
+        namespace: impl Into<Cow<'static, str>>,
+        tag: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            namespace: Some(namespace.into()),
+            ..Self::new(tag)
+        }
+    }
+}
+
+impl<A, V, E> Html<A, V, E> {
+    pub fn modify<A2>(self, modify: A2) -> Html<(A, A2), V, E> {
+        Html {
+            tag: self.tag,
+            namespace: self.namespace,
+            modify: (self.modify, modify),
+            view: self.view,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn on<F>(self, name: impl Into<Cow<'static, str>>, handler: F) -> Html<(A, On<F>), V, E>
+    where
+        F: FnMut(Event) -> E + 'static,
+        E: 'static,
+    {
+        self.modify(on(name, handler))
+    }
+
+    /// Add an event listener for events of type `T`, ignoring events of other types.
+    pub fn on_event<T, F>(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        handler: F,
+    ) -> Html<(A, OnEvent<T, F>), V, E>
+    where
+        T: JsCast,
+        F: FnMut(T) -> E + 'static,
+        E: 'static,
+    {
+        self.modify(on_event(name, handler))
+    }
+
+    pub fn on_click<F>(self, handler: F) -> Html<(A, OnEvent<MouseEvent, F>), V, E>
+    where
+        F: FnMut(MouseEvent) -> E + 'static,
+        E: 'static,
+    {
+        self.on_event("click", handler)
+    }
+
+    pub fn on_dblclick<F>(self, handler: F) -> Html<(A, OnEvent<MouseEvent, F>), V, E>
+    where
+        F: FnMut(MouseEvent) -> E + 'static,
+        E: 'static,
+    {
+        self.on_event("dblclick", handler)
+    }
+
+    /// Handle `input` events, which are sent whenever the value of an element changes.
+    ///
+    /// Use [`EventExt::target_value`](crate::web::EventExt::target_value) to read the new value.
+    /// This is a plain `Event` because `select` elements, checkboxes and radio buttons
+    /// don't send an `InputEvent`.
+    pub fn on_input<F>(self, handler: F) -> Html<(A, On<F>), V, E>
+    where
+        F: FnMut(Event) -> E + 'static,
+        E: 'static,
+    {
+        self.on("input", handler)
+    }
+
+    /// Handle `change` events, which are sent when the user commits a new value.
+    pub fn on_change<F>(self, handler: F) -> Html<(A, On<F>), V, E>
+    where
+        F: FnMut(Event) -> E + 'static,
+        E: 'static,
+    {
+        self.on("change", handler)
+    }
+
+    pub fn on_keydown<F>(self, handler: F) -> Html<(A, OnEvent<KeyboardEvent, F>), V, E>
+    where
+        F: FnMut(KeyboardEvent) -> E + 'static,
+        E: 'static,
+    {
+        self.on_event("keydown", handler)
+    }
+
+    pub fn on_keyup<F>(self, handler: F) -> Html<(A, OnEvent<KeyboardEvent, F>), V, E>
+    where
+        F: FnMut(KeyboardEvent) -> E + 'static,
+        E: 'static,
+    {
+        self.on_event("keyup", handler)
+    }
+
+    /// Handle `submit` events from a form.
+    ///
+    /// Call `prevent_default` on the event to keep the browser from navigating.
+    /// This is a plain `Event` because not every browser sends a `SubmitEvent`.
+    pub fn on_submit<F>(self, handler: F) -> Html<(A, On<F>), V, E>
+    where
+        F: FnMut(Event) -> E + 'static,
+        E: 'static,
+    {
+        self.on("submit", handler)
+    }
+
+    pub fn on_focus<F>(self, handler: F) -> Html<(A, OnEvent<FocusEvent, F>), V, E>
+    where
+        F: FnMut(FocusEvent) -> E + 'static,
+        E: 'static,
+    {
+        self.on_event("focus", handler)
+    }
+
+    pub fn on_blur<F>(self, handler: F) -> Html<(A, OnEvent<FocusEvent, F>), V, E>
+    where
+        F: FnMut(FocusEvent) -> E + 'static,
+        E: 'static,
+    {
+        self.on_event("blur", handler)
+    }
+
+    pub fn attr(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl AttrValue,
+    ) -> Html<(A, Attr), V, E> {
+        self.modify(attr(name, value))
+    }
+
+    /// Set an inline style property.
+    ///
+    /// This is named `css` because [`Html::style`] creates a `style` element.
+    pub fn css(
+        self,
+        property: impl Into<Cow<'static, str>>,
+        value: impl StyleValue,
+    ) -> Html<(A, Style), V, E> {
+        self.modify(style::style(property, value))
+    }
+
+    pub fn disabled(self, is_disabled: bool) -> Html<(A, Attr), V, E> {
+        self.modify(attr::disabled(is_disabled))
+    }
+
+    pub fn checked(self, is_checked: bool) -> Html<(A, Attr), V, E> {
+        self.modify(attr::checked(is_checked))
+    }
+
+    pub fn hidden(self, is_hidden: bool) -> Html<(A, Attr), V, E> {
+        self.modify(attr::hidden(is_hidden))
+    }
+
+    pub fn class(self, value: impl Into<Cow<'static, str>>) -> Html<(A, Attr), V, E> {
+        self.modify(class(value))
+    }
+
+    pub fn classes<I>(self, classes: I) -> Html<(A, Classes), V, E>
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        self.modify(classes::classes(classes))
+    }
+
+    pub fn class_if(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        condition: bool,
+    ) -> Html<(A, ClassIf), V, E> {
+        self.modify(classes::class_if(name, condition))
+    }
+
+    pub fn value(self, value: impl Into<Cow<'static, str>>) -> Html<(A, Value), V, E> {
+        self.modify(value::value(value))
+    }
+
+    pub fn view<V2>(self, view: V2) -> Html<A, (V, V2), E> {
+        Html {
+            tag: self.tag,
+            namespace: self.namespace,
+            modify: self.modify,
+            view: (self.view, view),
+            _marker: PhantomData,
+        }
+    }
+}
+
+macro_rules! html_tags {
+    ($($tag:ident),+) => {
+        $(
+            #[allow(clippy::self_named_constructors)]
+            pub fn $tag() -> Self {
+                Html::new(stringify!($tag))
+            }
+        )+
+    };
+}
+
+impl<E> Html<(), (), E> {
+    html_tags!(
+        a, abbr, address, area, article, aside, audio, b, base, bdi, bdo, blockquote, body, br,
+        button, canvas, caption, cite, code, col, colgroup, data, datalist, dd, del, details, dfn,
+        dialog, div, dl, dt, em, embed, fieldset, figcaption, figure, footer, form, h1, h2, h3, h4,
+        h5, h6, head, header, hgroup, hr, html, i, iframe, img, input, ins, kbd, label, legend, li,
+        link, main, map, mark, meta, meter, nav, noscript, object, ol, optgroup, option, output, p,
+        param, picture, pre, progress, q, rp, rt, ruby, s, samp, script, section, select, small,
+        source, span, strong, style, sub, summary, sup, table, tbody, td, template, textarea,
+        tfoot, th, thead, time, title, tr, track, u, ul, var, video, wbr
+    );
+}
@@ -0,0 +1,101 @@
+use super::Html;
+
+/// Namespace URI of SVG elements.
+pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// Namespace URI of MathML elements.
+pub const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+macro_rules! ns_tags {
+    ($namespace:expr, $($name:ident => $tag:literal),+) => {
+        $(
+            pub fn $name<E>() -> Html<(), (), E> {
+                Html::new_ns($namespace, $tag)
+            }
+        )+
+    };
+}
+
+/// Builders for SVG elements.
+///
+/// ```ignore
+/// Svg::svg()
+///     .attr("viewBox", "0 0 100 100")
+///     .view(Svg::circle().attr("cx", "50").attr("cy", "50").attr("r", "40"))
+/// ```
+pub struct Svg;
+
+impl Svg {
+    ns_tags!(
+        SVG_NAMESPACE,
+        a => "a",
+        animate => "animate",
+        animate_motion => "animateMotion",
+        animate_transform => "animateTransform",
+        circle => "circle",
+        clip_path => "clipPath",
+        defs => "defs",
+        desc => "desc",
+        ellipse => "ellipse",
+        filter => "filter",
+        foreign_object => "foreignObject",
+        g => "g",
+        image => "image",
+        line => "line",
+        linear_gradient => "linearGradient",
+        marker => "marker",
+        mask => "mask",
+        path => "path",
+        pattern => "pattern",
+        polygon => "polygon",
+        polyline => "polyline",
+        radial_gradient => "radialGradient",
+        rect => "rect",
+        stop => "stop",
+        svg => "svg",
+        switch => "switch",
+        symbol => "symbol",
+        text => "text",
+        text_path => "textPath",
+        title => "title",
+        tspan => "tspan",
+        r#use => "use",
+        view => "view"
+    );
+}
+
+/// Builders for MathML elements.
+pub struct MathMl;
+
+impl MathMl {
+    ns_tags!(
+        MATHML_NAMESPACE,
+        annotation => "annotation",
+        math => "math",
+        merror => "merror",
+        mfrac => "mfrac",
+        mi => "mi",
+        mmultiscripts => "mmultiscripts",
+        mn => "mn",
+        mo => "mo",
+        mover => "mover",
+        mpadded => "mpadded",
+        mphantom => "mphantom",
+        mroot => "mroot",
+        mrow => "mrow",
+        ms => "ms",
+        mspace => "mspace",
+        msqrt => "msqrt",
+        mstyle => "mstyle",
+        msub => "msub",
+        msubsup => "msubsup",
+        msup => "msup",
+        mtable => "mtable",
+        mtd => "mtd",
+        mtext => "mtext",
+        mtr => "mtr",
+        munder => "munder",
+        munderover => "munderover",
+        semantics => "semantics"
+    );
+}
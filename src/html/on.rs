@@ -0,0 +1,80 @@
+use std::{borrow::Cow, marker::PhantomData};
+use wasm_bindgen::JsCast;
+use web_sys::Event;
+
+/// Add an event listener to an element.
+///
+/// The event type `name` will listen for events using the `handler` function and
+/// emit the returned messages.
+pub fn on<F, E>(name: impl Into<Cow<'static, str>>, handler: F) -> On<F>
+where
+    F: FnMut(Event) -> E + 'static,
+    E: 'static,
+{
+    On {
+        name: name.into(),
+        handler,
+    }
+}
+
+/// Add an event listener for events of type `T`, like `MouseEvent` for `"click"`.
+///
+/// Events that aren't a `T` are ignored instead of being passed to `handler`.
+pub fn on_event<T, F, E>(name: impl Into<Cow<'static, str>>, handler: F) -> OnEvent<T, F>
+where
+    T: JsCast,
+    F: FnMut(T) -> E + 'static,
+    E: 'static,
+{
+    On {
+        name: name.into(),
+        handler: Typed {
+            handler,
+            _marker: PhantomData,
+        },
+    }
+}
+
+/// Modifier for the [`on_event`] function.
+pub type OnEvent<T, F> = On<Typed<T, F>>;
+
+/// Function to handle an event and return a message, or `None` to ignore it.
+pub trait Handler<E> {
+    fn handle(&mut self, event: Event) -> Option<E>;
+}
+
+impl<F, E> Handler<E> for F
+where
+    F: FnMut(Event) -> E,
+{
+    fn handle(&mut self, event: Event) -> Option<E> {
+        Some(self(event))
+    }
+}
+
+/// Handler for the [`on_event`] function, which casts events to `T`.
+pub struct Typed<T, F> {
+    handler: F,
+    _marker: PhantomData<fn(T)>,
+}
+
+impl<T, F, E> Handler<E> for Typed<T, F>
+where
+    T: JsCast,
+    F: FnMut(T) -> E,
+{
+    fn handle(&mut self, event: Event) -> Option<E> {
+        event.dyn_into().ok().map(&mut self.handler)
+    }
+}
+
+/// Modifier for the `on` function.
+pub struct On<F> {
+    /// Event name
+    #[cfg_attr(not(feature = "web"), allow(dead_code))]
+    pub(crate) name: Cow<'static, str>,
+
+    /// Function to handle an event and return a message.
+    #[cfg_attr(not(feature = "web"), allow(dead_code))]
+    pub(crate) handler: F,
+}
@@ -0,0 +1,147 @@
+use std::{borrow::Cow, fmt};
+
+/// Value of a style property, where `None` removes the property.
+pub trait StyleValue {
+    fn into_style_value(self) -> Option<Cow<'static, str>>;
+}
+
+impl StyleValue for Cow<'static, str> {
+    fn into_style_value(self) -> Option<Cow<'static, str>> {
+        Some(self)
+    }
+}
+
+impl StyleValue for &'static str {
+    fn into_style_value(self) -> Option<Cow<'static, str>> {
+        Some(self.into())
+    }
+}
+
+impl StyleValue for String {
+    fn into_style_value(self) -> Option<Cow<'static, str>> {
+        Some(self.into())
+    }
+}
+
+impl StyleValue for Length {
+    fn into_style_value(self) -> Option<Cow<'static, str>> {
+        Some(self.to_string().into())
+    }
+}
+
+impl<T: StyleValue> StyleValue for Option<T> {
+    fn into_style_value(self) -> Option<Cow<'static, str>> {
+        self.and_then(StyleValue::into_style_value)
+    }
+}
+
+/// CSS length with a unit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Length {
+    Auto,
+    Px(f64),
+    Em(f64),
+    Rem(f64),
+    Percent(f64),
+    Vw(f64),
+    Vh(f64),
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Length::Auto => f.write_str("auto"),
+            Length::Px(n) => write!(f, "{}px", n),
+            Length::Em(n) => write!(f, "{}em", n),
+            Length::Rem(n) => write!(f, "{}rem", n),
+            Length::Percent(n) => write!(f, "{}%", n),
+            Length::Vw(n) => write!(f, "{}vw", n),
+            Length::Vh(n) => write!(f, "{}vh", n),
+        }
+    }
+}
+
+/// Common CSS properties.
+///
+/// Any other property can be set by its name as a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CssProperty {
+    BackgroundColor,
+    Border,
+    Bottom,
+    Color,
+    Cursor,
+    Display,
+    FontSize,
+    FontWeight,
+    Height,
+    Left,
+    Margin,
+    MaxHeight,
+    MaxWidth,
+    MinHeight,
+    MinWidth,
+    Opacity,
+    Padding,
+    Position,
+    Right,
+    Top,
+    Transform,
+    Visibility,
+    Width,
+    ZIndex,
+}
+
+impl CssProperty {
+    /// The name of this property in CSS.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CssProperty::BackgroundColor => "background-color",
+            CssProperty::Border => "border",
+            CssProperty::Bottom => "bottom",
+            CssProperty::Color => "color",
+            CssProperty::Cursor => "cursor",
+            CssProperty::Display => "display",
+            CssProperty::FontSize => "font-size",
+            CssProperty::FontWeight => "font-weight",
+            CssProperty::Height => "height",
+            CssProperty::Left => "left",
+            CssProperty::Margin => "margin",
+            CssProperty::MaxHeight => "max-height",
+            CssProperty::MaxWidth => "max-width",
+            CssProperty::MinHeight => "min-height",
+            CssProperty::MinWidth => "min-width",
+            CssProperty::Opacity => "opacity",
+            CssProperty::Padding => "padding",
+            CssProperty::Position => "position",
+            CssProperty::Right => "right",
+            CssProperty::Top => "top",
+            CssProperty::Transform => "transform",
+            CssProperty::Visibility => "visibility",
+            CssProperty::Width => "width",
+            CssProperty::ZIndex => "z-index",
+        }
+    }
+}
+
+impl From<CssProperty> for Cow<'static, str> {
+    fn from(value: CssProperty) -> Self {
+        Cow::Borrowed(value.as_str())
+    }
+}
+
+/// Set an inline style property for an element.
+///
+/// The property is removed if `value` is `None`.
+pub fn style(property: impl Into<Cow<'static, str>>, value: impl StyleValue) -> Style {
+    Style {
+        property: property.into(),
+        value: value.into_style_value(),
+    }
+}
+
+/// Modifier for the [`style`] function.
+pub struct Style {
+    pub(crate) property: Cow<'static, str>,
+    pub(crate) value: Option<Cow<'static, str>>,
+}
@@ -0,0 +1,13 @@
+use std::borrow::Cow;
+
+/// Set the value attribute of an element.
+pub fn value(value: impl Into<Cow<'static, str>>) -> Value {
+    Value {
+        value: value.into(),
+    }
+}
+
+/// View for the [`value`] function.
+pub struct Value {
+    pub(crate) value: Cow<'static, str>,
+}
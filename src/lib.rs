@@ -5,21 +5,37 @@
 //! Concoct is statically-typed UI library for building applications with Rust
 //! that run on multiple platforms.
 
+mod command;
+pub use command::Command;
+
 mod modify;
 
 pub use modify::Modify;
 
+mod sub;
+pub use sub::Sub;
+
 pub mod view;
 pub use view::View;
 
+pub mod testing;
+
 #[cfg(feature = "native")]
 #[cfg_attr(docsrs, doc(cfg(feature = "native")))]
 pub mod native;
 
+#[cfg(any(feature = "web", feature = "ssr"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "web", feature = "ssr"))))]
+pub mod html;
+
 #[cfg(feature = "web")]
 #[cfg_attr(docsrs, doc(cfg(feature = "web")))]
 pub mod web;
 
+#[cfg(feature = "ssr")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ssr")))]
+pub mod ssr;
+
 /// Backend rendering platform.
 pub trait Platform {
     type Event;
@@ -27,4 +43,14 @@ pub trait Platform {
     /// Advance the element count.
     /// This should be called when a view is skipped.
     fn advance(&mut self);
+
+    /// Current element index within the parent.
+    fn index(&self) -> usize;
+
+    /// Move `len` elements of the current parent from index `from` to index `to`,
+    /// where `to` is an index into the remaining elements once the moved ones are taken out.
+    fn move_nodes(&mut self, from: usize, len: usize, to: usize);
+
+    /// Values provided to the current view by its ancestors.
+    fn contexts(&mut self) -> &mut view::Contexts;
 }
@@ -9,6 +9,7 @@ pub trait Modify<P: Platform, E> {
     fn rebuild(self, cx: &mut P, elem: &mut E, state: &mut Self::State);
 }
 
+#[allow(clippy::unused_unit)]
 #[impl_for_tuples(16)]
 impl<P: Platform, E> Modify<P, E> for Tuple {
     for_tuples!( type State = ( #( Tuple::State ),* ); );
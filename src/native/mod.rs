@@ -1,4 +1,8 @@
-use crate::{view::View, Platform};
+use crate::{
+    sub::Subscriptions,
+    view::{Contexts, View},
+    Command, Platform, Sub,
+};
 use accesskit::Point;
 use gl::types::*;
 use glutin::{
@@ -19,11 +23,16 @@ use skia_safe::{
 };
 use slotmap::{DefaultKey, SlotMap};
 use std::{
+    cell::RefCell,
+    collections::VecDeque,
     ffi::CString,
-    marker::PhantomData,
+    future::Future,
+    mem,
     num::NonZeroU32,
+    rc::Rc,
     time::{Duration, Instant},
 };
+use tokio::{runtime::Runtime, task::LocalSet};
 use taffy::{
     prelude::Size,
     style::{FlexDirection, Style},
@@ -39,32 +48,109 @@ use winit::{
 mod element;
 pub use element::Element;
 
+mod sub;
+pub use sub::{animation_frame, interval, on_key_down, on_resize, on_window};
+
 pub mod view;
 
+pub struct Native<E> {
+    elements: SlotMap<DefaultKey, Box<dyn Element>>,
+    stack: Vec<DefaultKey>,
+    /// Position in `stack` of the next element.
+    idx: usize,
+    layout_keys: SlotMap<DefaultKey, DefaultKey>,
+    taffy: Taffy,
+    layout_stack: Vec<DefaultKey>,
+    contexts: Contexts,
+    pub update: Rc<dyn Fn(E)>,
+}
+
+impl<E> Native<E> {
+    /// Spawn a future on the event loop thread and send its output as a message.
+    ///
+    /// Futures run on the application's tokio runtime, so they can use its timers and IO.
+    pub fn spawn(&self, future: impl Future<Output = E> + 'static)
+    where
+        E: 'static,
+    {
+        let update = self.update.clone();
+        tokio::task::spawn_local(async move { update(future.await) });
+    }
+
+    /// Run `f` with a context that sends its messages through `update`.
+    pub fn with_update<E2, R>(
+        &mut self,
+        update: Rc<dyn Fn(E2)>,
+        f: impl FnOnce(&mut Native<E2>) -> R,
+    ) -> R {
+        let mut cx = Native {
+            elements: mem::take(&mut self.elements),
+            stack: mem::take(&mut self.stack),
+            idx: self.idx,
+            layout_keys: mem::take(&mut self.layout_keys),
+            taffy: mem::take(&mut self.taffy),
+            layout_stack: mem::take(&mut self.layout_stack),
+            contexts: mem::take(&mut self.contexts),
+            update,
+        };
+        let output = f(&mut cx);
+
+        self.elements = cx.elements;
+        self.stack = cx.stack;
+        self.idx = cx.idx;
+        self.layout_keys = cx.layout_keys;
+        self.taffy = cx.taffy;
+        self.layout_stack = cx.layout_stack;
+        self.contexts = cx.contexts;
+        output
+    }
+}
+
 // Guarantee the drop order inside the FnMut closure. `Window` _must_ be dropped after
 // `DirectContext`.
 //
 // https://github.com/rust-skia/rust-skia/issues/476
-pub struct Native<E> {
+struct Renderer {
     surface: Surface,
     gl_surface: GlutinSurface<WindowSurface>,
     gr_context: skia_safe::gpu::DirectContext,
     gl_context: PossiblyCurrentContext,
     window: Window,
-    _marker: PhantomData<E>,
-    elements: SlotMap<DefaultKey, Box<dyn Element>>,
-    stack: Vec<DefaultKey>,
-    layout_keys: SlotMap<DefaultKey, DefaultKey>,
-    taffy: Taffy,
-    layout_stack: Vec<DefaultKey>,
 }
 
 impl<E> Platform for Native<E> {
     type Event = E;
 
     fn advance(&mut self) {
-        todo!()
+        self.idx += 1;
+    }
+
+    fn index(&self) -> usize {
+        self.idx
     }
+
+    fn move_nodes(&mut self, from: usize, len: usize, to: usize) {
+        if from == to || len == 0 {
+            return;
+        }
+
+        // Each element has a layout node at the same position
+        for keys in [&mut self.stack, &mut self.layout_stack] {
+            let moved: Vec<_> = keys.drain(from..from + len).collect();
+            keys.splice(to..to, moved);
+        }
+    }
+
+    fn contexts(&mut self) -> &mut Contexts {
+        &mut self.contexts
+    }
+}
+
+/// Command that runs a future on the event loop thread and sends its output as a message.
+pub fn perform<E: 'static>(future: impl Future<Output = E> + 'static) -> Command<E> {
+    Command::effect(move |send| {
+        tokio::task::spawn_local(async move { send(future.await) });
+    })
 }
 
 #[derive(Clone, Debug)]
@@ -72,18 +158,30 @@ pub enum Event {
     MouseMove { pos: Point },
 }
 
-pub fn run<T, V, E>(
+/// Run a native application.
+///
+/// Messages are handled by `update`, which may return a [`Command`]
+/// whose messages are handled in turn before the next frame.
+/// After each update the [`Sub`] returned from `subscriptions` is diffed against the
+/// running subscriptions.
+pub fn run<T, V, E, C>(
     mut state: T,
-    _update: impl Fn(&mut T, E) + 'static,
+    update: impl Fn(&mut T, E) -> C + 'static,
     mut make_view: impl FnMut(&T) -> V + 'static,
+    subscriptions: impl Fn(&T) -> Sub<E> + 'static,
 ) where
     T: 'static,
     V: View<Native<E>> + 'static,
     V::State: 'static,
     E: 'static,
+    C: Into<Command<E>>,
 {
     let el = EventLoopBuilder::with_user_event().build();
 
+    // Spawned futures run on the event loop thread, driven once per iteration
+    let runtime = Runtime::new().expect("Could not create tokio runtime");
+    let local = LocalSet::new();
+
     let winit_window_builder = WindowBuilder::new().with_title("concoct");
 
     let template = ConfigTemplateBuilder::new()
@@ -226,23 +324,42 @@ pub fn run<T, V, E>(
 
     let mut frame = 0usize;
 
-    let mut env = Native {
+    let mut renderer = Renderer {
         surface,
         gl_surface,
         gl_context,
         gr_context,
         window,
-        _marker: PhantomData,
+    };
+
+    // Queue messages and wake up the event loop to handle them
+    let queue = Rc::new(RefCell::new(VecDeque::new()));
+    let update_queue = queue.clone();
+    let proxy = el.create_proxy();
+
+    let mut env = Native {
         elements: SlotMap::new(),
         stack: Vec::new(),
+        idx: 0,
         layout_keys: SlotMap::new(),
         taffy: Taffy::new(),
         layout_stack: Vec::new(),
+        contexts: Contexts::default(),
+        update: Rc::new(move |msg| {
+            update_queue.borrow_mut().push_back(msg);
+            proxy.send_event(()).ok();
+        }),
     };
     let mut previous_frame_start = Instant::now();
 
     let view = make_view(&mut state);
-    let mut view_state = view.build(&mut env);
+    let mut subs = Subscriptions::default();
+    let mut view_state = {
+        let _runtime = runtime.enter();
+        let _local = local.enter();
+        subs.update(subscriptions(&state), &env.update);
+        view.build(&mut env)
+    };
 
     let mut layout = Style::default();
     layout.size = Size::from_points(1000., 1000.);
@@ -255,12 +372,30 @@ pub fn run<T, V, E>(
     taffy::compute_layout(&mut env.taffy, root, Size::MAX_CONTENT).unwrap();
 
     el.run(move |event, _, control_flow| {
+        // Poll spawned futures that are ready
+        runtime.block_on(local.run_until(tokio::task::yield_now()));
+        let _runtime = runtime.enter();
+        let _local = local.enter();
+
         let frame_start = Instant::now();
         let mut draw_frame = false;
 
+        if let WinitEvent::WindowEvent { event, .. } = &event {
+            sub::dispatch_window_event(event);
+        }
+
         #[allow(deprecated)]
         match event {
-            WinitEvent::UserEvent(()) => {}
+            WinitEvent::UserEvent(()) => {
+                loop {
+                    let Some(msg) = queue.borrow_mut().pop_front() else {
+                        break;
+                    };
+                    update(&mut state, msg).into().run(env.update.clone());
+                }
+                subs.update(subscriptions(&state), &env.update);
+                renderer.window.request_redraw();
+            }
             WinitEvent::LoopDestroyed => {}
             WinitEvent::WindowEvent { event, .. } => match event {
                 WindowEvent::CloseRequested => {
@@ -268,18 +403,18 @@ pub fn run<T, V, E>(
                     return;
                 }
                 WindowEvent::Resized(physical_size) => {
-                    env.surface = create_surface(
-                        &mut env.window,
+                    renderer.surface = create_surface(
+                        &mut renderer.window,
                         fb_info,
-                        &mut env.gr_context,
+                        &mut renderer.gr_context,
                         num_samples,
                         stencil_size,
                     );
                     /* First resize the opengl drawable */
                     let (width, height): (u32, u32) = physical_size.into();
 
-                    env.gl_surface.resize(
-                        &env.gl_context,
+                    renderer.gl_surface.resize(
+                        &renderer.gl_context,
                         NonZeroU32::new(width.max(1)).unwrap(),
                         NonZeroU32::new(height.max(1)).unwrap(),
                     );
@@ -299,7 +434,7 @@ pub fn run<T, V, E>(
                         }
                     }
                     frame = frame.saturating_sub(10);
-                    env.window.request_redraw();
+                    renderer.window.request_redraw();
                 }
                 WindowEvent::CursorMoved {
                     device_id: _,
@@ -326,19 +461,25 @@ pub fn run<T, V, E>(
         }
         if draw_frame {
             frame += 1;
-            let canvas = env.surface.canvas();
+            sub::dispatch_frame(frame_start);
+
+            let canvas = renderer.surface.canvas();
             canvas.clear(Color::WHITE);
 
             let tree = make_view(&mut state);
+            env.idx = 0;
             tree.rebuild(&mut env, &mut view_state);
 
             for key in &env.stack {
                 let root = env.elements.get_mut(*key).unwrap();
-                root.paint(&env.taffy, env.surface.canvas());
+                root.paint(&env.taffy, renderer.surface.canvas());
             }
 
-            env.gr_context.flush_and_submit();
-            env.gl_surface.swap_buffers(&env.gl_context).unwrap();
+            renderer.gr_context.flush_and_submit();
+            renderer
+                .gl_surface
+                .swap_buffers(&renderer.gl_context)
+                .unwrap();
         }
 
         *control_flow = ControlFlow::WaitUntil(previous_frame_start + frame_duration)
@@ -0,0 +1,161 @@
+use crate::Sub;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+use winit::event::{ElementState, KeyboardInput, VirtualKeyCode, WindowEvent};
+
+enum GlobalEvent<'a> {
+    Window(&'a WindowEvent<'a>),
+    Frame(Instant),
+}
+
+type Listener = Rc<dyn Fn(&GlobalEvent)>;
+
+#[derive(Default)]
+struct Listeners {
+    next_id: usize,
+    listeners: Vec<(usize, Listener)>,
+}
+
+thread_local! {
+    // Listeners for events from the event loop, which runs on a single thread
+    static LISTENERS: RefCell<Listeners> = RefCell::default();
+}
+
+fn listen<E: 'static>(
+    send: Rc<dyn Fn(E)>,
+    f: impl Fn(&GlobalEvent) -> Option<E> + 'static,
+) -> impl FnOnce() {
+    let listener: Listener = Rc::new(move |event| {
+        if let Some(msg) = f(event) {
+            send(msg)
+        }
+    });
+    let id = LISTENERS.with(|cell| {
+        let listeners = &mut *cell.borrow_mut();
+        let id = listeners.next_id;
+        listeners.next_id += 1;
+        listeners.listeners.push((id, listener));
+        id
+    });
+
+    move || {
+        LISTENERS.with(|cell| {
+            cell.borrow_mut()
+                .listeners
+                .retain(|(listener_id, _)| *listener_id != id)
+        })
+    }
+}
+
+fn dispatch(event: &GlobalEvent) {
+    let listeners: Vec<_> = LISTENERS.with(|cell| {
+        cell.borrow()
+            .listeners
+            .iter()
+            .map(|(_, listener)| listener.clone())
+            .collect()
+    });
+
+    for listener in listeners {
+        listener(event)
+    }
+}
+
+/// Send a window event to subscriptions.
+pub(super) fn dispatch_window_event(event: &WindowEvent) {
+    dispatch(&GlobalEvent::Window(event))
+}
+
+/// Send the start of a frame to subscriptions.
+pub(super) fn dispatch_frame(instant: Instant) {
+    dispatch(&GlobalEvent::Frame(instant))
+}
+
+/// Subscription to a timer that fires every `duration`.
+pub fn interval<E, F>(duration: Duration, f: F) -> Sub<E>
+where
+    F: Fn() -> E + 'static,
+    E: 'static,
+{
+    Sub::new(duration, move |send| {
+        let handle = tokio::task::spawn_local(async move {
+            let start = tokio::time::Instant::now() + duration;
+            let mut interval = tokio::time::interval_at(start, duration);
+            loop {
+                interval.tick().await;
+                send(f());
+            }
+        });
+
+        move || handle.abort()
+    })
+}
+
+/// Subscription to frames.
+///
+/// The handler is called with the time the frame started.
+pub fn animation_frame<E, F>(f: F) -> Sub<E>
+where
+    F: Fn(Instant) -> E + 'static,
+    E: 'static,
+{
+    Sub::new((), move |send| {
+        listen(send, move |event| match event {
+            GlobalEvent::Frame(instant) => Some(f(*instant)),
+            _ => None,
+        })
+    })
+}
+
+/// Subscription to window events.
+///
+/// The handler can return `None` to ignore an event.
+pub fn on_window<E, F>(f: F) -> Sub<E>
+where
+    F: Fn(&WindowEvent) -> Option<E> + 'static,
+    E: 'static,
+{
+    Sub::new((), move |send| {
+        listen(send, move |event| match event {
+            GlobalEvent::Window(event) => f(event),
+            _ => None,
+        })
+    })
+}
+
+/// Subscription to window resizes.
+///
+/// The handler is called with the new inner width and height of the window.
+pub fn on_resize<E, F>(f: F) -> Sub<E>
+where
+    F: Fn(u32, u32) -> E + 'static,
+    E: 'static,
+{
+    on_window(move |event| match event {
+        WindowEvent::Resized(size) => Some(f(size.width, size.height)),
+        _ => None,
+    })
+}
+
+/// Subscription to key presses.
+pub fn on_key_down<E, F>(f: F) -> Sub<E>
+where
+    F: Fn(VirtualKeyCode) -> E + 'static,
+    E: 'static,
+{
+    on_window(move |event| match event {
+        WindowEvent::KeyboardInput {
+            input:
+                KeyboardInput {
+                    state: ElementState::Pressed,
+                    virtual_keycode: Some(key_code),
+                    ..
+                },
+            ..
+        } => Some(f(*key_code)),
+        _ => None,
+    })
+}
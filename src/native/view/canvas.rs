@@ -1,6 +1,6 @@
 use crate::{
     native::{Element, Native},
-    View,
+    Platform, View,
 };
 use slotmap::DefaultKey;
 use taffy::{
@@ -24,24 +24,36 @@ impl<E, F> View<Native<E>> for Canvas<F>
 where
     F: FnMut(&Layout, &mut skia_safe::Canvas) + 'static,
 {
-    type State = ();
+    /// The key of the canvas element.
+    type State = DefaultKey;
 
     fn build(self, cx: &mut Native<E>) -> Self::State {
         let mut layout = Style::default();
         layout.size = Size::from_points(200., 200.);
         let layout_key = cx.taffy.new_leaf(layout).unwrap();
-        cx.layout_stack.push(layout_key);
+        cx.layout_stack.insert(cx.idx, layout_key);
 
         let key = cx.elements.insert(Box::new(CanvasElement {
             layout_key,
             draw: self.draw,
         }));
-        cx.stack.push(key);
+        cx.stack.insert(cx.idx, key);
+        cx.advance();
+
+        key
     }
 
-    fn rebuild(self, _cx: &mut Native<E>, _state: &mut Self::State) {}
+    fn rebuild(self, cx: &mut Native<E>, _state: &mut Self::State) {
+        cx.advance();
+    }
 
-    fn remove(_cx: &mut Native<E>, _state: &mut Self::State) {}
+    fn remove(cx: &mut Native<E>, key: &mut Self::State) {
+        let idx = cx.stack.iter().position(|k| k == key).unwrap();
+        cx.stack.remove(idx);
+        let layout_key = cx.layout_stack.remove(idx);
+        cx.taffy.remove(layout_key).unwrap();
+        cx.elements.remove(*key);
+    }
 }
 
 struct CanvasElement<F> {
@@ -0,0 +1,112 @@
+use super::{Element, Ssr};
+use crate::{
+    html::{Attr, ClassIf, Classes, Html, On, Style, Value},
+    view::View,
+    Modify, Platform,
+};
+
+impl<A, V, E> View<Ssr<E>> for Html<A, V, E>
+where
+    A: Modify<Ssr<E>, Element>,
+    V: View<Ssr<E>>,
+{
+    type State = (A::State, V::State);
+
+    fn build(self, cx: &mut Ssr<E>) -> Self::State {
+        let mut element = cx.create_element(self.tag);
+        let modify = self.modify.build(cx, &mut element);
+        let view = cx.write_element(&element, |cx| self.view.build(cx));
+        (modify, view)
+    }
+
+    fn rebuild(self, cx: &mut Ssr<E>, _state: &mut Self::State) {
+        cx.advance();
+    }
+
+    fn remove(_cx: &mut Ssr<E>, _state: &mut Self::State) {}
+}
+
+impl<E> Modify<Ssr<E>, Element> for Attr {
+    type State = ();
+
+    fn build(self, _cx: &mut Ssr<E>, elem: &mut Element) -> Self::State {
+        if let Some(value) = self.value {
+            elem.set_attribute(self.name, value)
+        }
+    }
+
+    fn rebuild(self, _cx: &mut Ssr<E>, _elem: &mut Element, _state: &mut Self::State) {}
+}
+
+impl<E> Modify<Ssr<E>, Element> for Classes {
+    type State = ();
+
+    fn build(self, _cx: &mut Ssr<E>, elem: &mut Element) -> Self::State {
+        for class in &self.classes {
+            add_class(elem, class);
+        }
+    }
+
+    fn rebuild(self, _cx: &mut Ssr<E>, _elem: &mut Element, _state: &mut Self::State) {}
+}
+
+impl<E> Modify<Ssr<E>, Element> for ClassIf {
+    type State = ();
+
+    fn build(self, _cx: &mut Ssr<E>, elem: &mut Element) -> Self::State {
+        if self.condition {
+            add_class(elem, &self.name);
+        }
+    }
+
+    fn rebuild(self, _cx: &mut Ssr<E>, _elem: &mut Element, _state: &mut Self::State) {}
+}
+
+/// Styles are rendered to the `style` attribute.
+impl<E> Modify<Ssr<E>, Element> for Style {
+    type State = ();
+
+    fn build(self, _cx: &mut Ssr<E>, elem: &mut Element) -> Self::State {
+        if let Some(value) = self.value {
+            let mut style = elem.attribute("style").unwrap_or_default().to_owned();
+            style.push_str(&format!("{}: {};", self.property, value));
+            elem.set_attribute("style", style);
+        }
+    }
+
+    fn rebuild(self, _cx: &mut Ssr<E>, _elem: &mut Element, _state: &mut Self::State) {}
+}
+
+/// The value is rendered as the `value` attribute.
+impl<E> Modify<Ssr<E>, Element> for Value {
+    type State = ();
+
+    fn build(self, _cx: &mut Ssr<E>, elem: &mut Element) -> Self::State {
+        elem.set_attribute("value", self.value)
+    }
+
+    fn rebuild(self, _cx: &mut Ssr<E>, _elem: &mut Element, _state: &mut Self::State) {}
+}
+
+/// Event handlers are ignored when rendering to a string.
+impl<F, E> Modify<Ssr<E>, Element> for On<F> {
+    type State = ();
+
+    fn build(self, _cx: &mut Ssr<E>, _elem: &mut Element) -> Self::State {}
+
+    fn rebuild(self, _cx: &mut Ssr<E>, _elem: &mut Element, _state: &mut Self::State) {}
+}
+
+/// Add a class to the `class` attribute of a rendered element.
+fn add_class(elem: &mut Element, class: &str) {
+    let mut value = elem.attribute("class").unwrap_or_default().to_owned();
+    if value.split_whitespace().any(|name| name == class) {
+        return;
+    }
+
+    if !value.is_empty() {
+        value.push(' ');
+    }
+    value.push_str(class);
+    elem.set_attribute("class", value);
+}
@@ -0,0 +1,303 @@
+//! Server-side rendering of views to HTML strings.
+//!
+//! [`Ssr`] writes [`Html`](crate::html::Html) views as HTML text instead of creating DOM
+//! nodes, so the views used in the browser can be rendered on a server for first paint.
+//! Event handlers are ignored.
+
+use crate::{view::Contexts, Platform, View};
+use std::{borrow::Cow, fmt::Write, marker::PhantomData, mem};
+
+mod html;
+
+/// Elements that can't have children or a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose text is written as-is, since entities aren't decoded inside of them.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+/// Render a view to an HTML string.
+pub fn render<E, V>(view: V) -> String
+where
+    V: View<Ssr<E>>,
+{
+    let mut cx = Ssr::new();
+    cx.build(view);
+    cx.into_string()
+}
+
+/// Element that is being rendered, before its start tag is written.
+pub struct Element {
+    tag: Cow<'static, str>,
+    attributes: Vec<(Cow<'static, str>, String)>,
+}
+
+impl Element {
+    /// The tag name of this element.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Get the value of an attribute.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attr_name, _)| attr_name == name)
+            .map(|(_, value)| &**value)
+    }
+
+    /// Set an attribute, replacing any previous value.
+    pub fn set_attribute(&mut self, name: impl Into<Cow<'static, str>>, value: impl Into<String>) {
+        let name = name.into();
+        let value = value.into();
+
+        if let Some((_, prev)) = self
+            .attributes
+            .iter_mut()
+            .find(|(attr_name, _)| *attr_name == name)
+        {
+            *prev = value;
+        } else {
+            self.attributes.push((name, value));
+        }
+    }
+}
+
+/// Server-side rendering platform.
+///
+/// Views are written as they are built. An `Ssr` is meant to render a view once,
+/// so rebuilding or removing views doesn't change the output.
+pub struct Ssr<E = ()> {
+    html: String,
+    stack: Vec<usize>,
+    contexts: Contexts,
+    is_raw_text: bool,
+    _marker: PhantomData<fn(E)>,
+}
+
+impl<E> Platform for Ssr<E> {
+    type Event = E;
+
+    fn advance(&mut self) {
+        *self.stack.last_mut().unwrap() += 1;
+    }
+
+    fn index(&self) -> usize {
+        *self.stack.last().unwrap()
+    }
+
+    fn move_nodes(&mut self, _from: usize, _len: usize, _to: usize) {}
+
+    fn contexts(&mut self) -> &mut Contexts {
+        &mut self.contexts
+    }
+}
+
+impl<E> Default for Ssr<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Ssr<E> {
+    /// Create a new platform with empty output.
+    pub fn new() -> Self {
+        Self {
+            html: String::new(),
+            stack: vec![0],
+            contexts: Contexts::default(),
+            is_raw_text: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Build a view, writing it to the output.
+    pub fn build<V: View<Self>>(&mut self, view: V) -> V::State {
+        view.build(self)
+    }
+
+    /// The HTML written so far.
+    pub fn as_str(&self) -> &str {
+        &self.html
+    }
+
+    /// Take the written HTML.
+    pub fn into_string(self) -> String {
+        self.html
+    }
+
+    /// Create an element with a tag name.
+    pub fn create_element(&self, tag: impl Into<Cow<'static, str>>) -> Element {
+        Element {
+            tag: tag.into(),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Write an element, using `f` to write its children.
+    ///
+    /// Void elements like `input` are written without children or a closing tag,
+    /// and text inside of `script` and `style` elements is written without escaping.
+    pub fn write_element<R>(&mut self, element: &Element, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.advance();
+
+        self.html.push('<');
+        self.html.push_str(&element.tag);
+        for (name, value) in &element.attributes {
+            write!(self.html, " {}=\"", name).unwrap();
+            escape(&mut self.html, value, true);
+            self.html.push('"');
+        }
+        self.html.push('>');
+
+        let is_void = VOID_ELEMENTS.contains(&&*element.tag);
+        let is_raw_text = mem::replace(
+            &mut self.is_raw_text,
+            RAW_TEXT_ELEMENTS.contains(&&*element.tag),
+        );
+
+        // Children of void elements are still built, but their output is discarded
+        let len = self.html.len();
+        self.stack.push(0);
+        let output = f(self);
+        self.stack.pop();
+        self.is_raw_text = is_raw_text;
+        if is_void {
+            self.html.truncate(len);
+        } else {
+            write!(self.html, "</{}>", element.tag).unwrap();
+        }
+
+        output
+    }
+
+    /// Write a text node, which is escaped unless it's inside of a `script` or `style` element.
+    ///
+    /// Raw text can't be escaped, so it must not contain the closing tag of its element.
+    pub fn write_text(&mut self, text: &str) {
+        self.advance();
+        if self.is_raw_text {
+            self.html.push_str(text);
+        } else {
+            escape(&mut self.html, text, false);
+        }
+    }
+
+    /// Run `f` with a context for views that emit messages of type `E2`.
+    pub fn with_event<E2, R>(&mut self, f: impl FnOnce(&mut Ssr<E2>) -> R) -> R {
+        let mut cx = Ssr {
+            html: mem::take(&mut self.html),
+            stack: mem::take(&mut self.stack),
+            contexts: mem::take(&mut self.contexts),
+            is_raw_text: self.is_raw_text,
+            _marker: PhantomData,
+        };
+        let output = f(&mut cx);
+
+        self.html = cx.html;
+        self.stack = cx.stack;
+        self.contexts = cx.contexts;
+        output
+    }
+}
+
+/// Escape `s` for an HTML text node, or for a quoted attribute value if `is_attr` is `true`.
+fn escape(html: &mut String, s: &str, is_attr: bool) {
+    for c in s.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' if is_attr => html.push_str("&quot;"),
+            c => html.push(c),
+        }
+    }
+}
+
+impl<E> View<Ssr<E>> for &'_ str {
+    type State = ();
+
+    fn build(self, cx: &mut Ssr<E>) -> Self::State {
+        cx.write_text(self);
+    }
+
+    fn rebuild(self, cx: &mut Ssr<E>, _state: &mut Self::State) {
+        cx.advance();
+    }
+
+    fn remove(_cx: &mut Ssr<E>, _state: &mut Self::State) {}
+}
+
+impl<E> View<Ssr<E>> for String {
+    type State = ();
+
+    fn build(self, cx: &mut Ssr<E>) -> Self::State {
+        cx.write_text(&self);
+    }
+
+    fn rebuild(self, cx: &mut Ssr<E>, _state: &mut Self::State) {
+        cx.advance();
+    }
+
+    fn remove(_cx: &mut Ssr<E>, _state: &mut Self::State) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::html::Html;
+
+    #[test]
+    fn escape_text() {
+        let html = render::<(), _>(Html::p().view("<script>&\"'</script>"));
+        assert_eq!(html, "<p>&lt;script&gt;&amp;\"'&lt;/script&gt;</p>");
+    }
+
+    #[test]
+    fn raw_text() {
+        let html = render::<(), _>((
+            Html::script().view("if (a < b && c > d) {}"),
+            Html::style().view("a > b { content: \"&\"; }"),
+        ));
+        assert_eq!(
+            html,
+            "<script>if (a < b && c > d) {}</script><style>a > b { content: \"&\"; }</style>"
+        );
+    }
+
+    #[test]
+    fn escape_attribute() {
+        let html = render::<(), _>(Html::div().attr("title", "\"><script>&"));
+        assert_eq!(html, "<div title=\"&quot;&gt;&lt;script&gt;&amp;\"></div>");
+    }
+
+    #[test]
+    fn void_element() {
+        let html = render::<(), _>((
+            Html::input().attr("type", "text").view("ignored"),
+            Html::br(),
+            "after",
+        ));
+        assert_eq!(html, "<input type=\"text\"><br>after");
+    }
+
+    #[test]
+    fn removed_attribute() {
+        let html = render::<(), _>(
+            Html::button()
+                .attr("title", None::<&'static str>)
+                .disabled(false)
+                .hidden(true)
+                .view("Ok"),
+        );
+        assert_eq!(html, "<button hidden=\"\">Ok</button>");
+    }
+
+    #[test]
+    fn ignore_event_handlers() {
+        let html = render(Html::button().on_click(|_| 1).view("Increment"));
+        assert_eq!(html, "<button>Increment</button>");
+    }
+}
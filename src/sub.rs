@@ -0,0 +1,181 @@
+use std::{
+    any::TypeId,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+#[cfg(any(feature = "web", feature = "native"))]
+use std::collections::HashMap;
+
+type Start<E> = Box<dyn FnOnce(Rc<dyn Fn(E)>) -> Box<dyn FnOnce()>>;
+
+/// Subscription to events that aren't tied to an element, like timers or window events.
+///
+/// Subscriptions are returned from the `subscriptions` function passed to a platform's `run`,
+/// which is called after every update. New subscriptions are started and ones that are no
+/// longer returned are stopped.
+///
+/// A subscription is identified by its kind and parameters, so a running subscription keeps
+/// the message function it was started with. Subscriptions of the same kind with the same
+/// parameters, like timers created in a loop, need a unique [`key`](Sub::key) to run together.
+pub struct Sub<E> {
+    recipes: Vec<(u64, Start<E>)>,
+}
+
+impl<E> Sub<E> {
+    /// Subscription to nothing.
+    pub fn none() -> Self {
+        Self {
+            recipes: Vec::new(),
+        }
+    }
+
+    /// Create a subscription identified by `id`.
+    ///
+    /// When started, `start` is called with a function to send messages and
+    /// returns a function to stop the subscription.
+    pub fn new<F, S>(id: impl Hash, start: F) -> Self
+    where
+        F: FnOnce(Rc<dyn Fn(E)>) -> S + 'static,
+        S: FnOnce() + 'static,
+    {
+        let mut hasher = DefaultHasher::new();
+        TypeId::of::<F>().hash(&mut hasher);
+        id.hash(&mut hasher);
+
+        Self {
+            recipes: vec![(
+                hasher.finish(),
+                Box::new(move |send| Box::new(start(send)) as Box<dyn FnOnce()>),
+            )],
+        }
+    }
+
+    /// Identify this subscription by `key` in addition to its kind and parameters.
+    ///
+    /// Only the first of several subscriptions with the same identity is started,
+    /// and the others are reported as warnings in debug builds.
+    ///
+    /// ```ignore
+    /// Sub::batch(
+    ///     timers
+    ///         .iter()
+    ///         .map(|timer| interval(timer.duration, move || Event::Tick(timer.id)).key(timer.id)),
+    /// )
+    /// ```
+    pub fn key(self, key: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let key = hasher.finish();
+
+        Self {
+            recipes: self
+                .recipes
+                .into_iter()
+                .map(|(id, start)| {
+                    let mut hasher = DefaultHasher::new();
+                    id.hash(&mut hasher);
+                    key.hash(&mut hasher);
+                    (hasher.finish(), start)
+                })
+                .collect(),
+        }
+    }
+
+    /// Combine multiple subscriptions into one.
+    pub fn batch(subs: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            recipes: subs.into_iter().flat_map(|sub| sub.recipes).collect(),
+        }
+    }
+
+    /// Map the messages of this subscription to another type.
+    pub fn map<F, E2>(self, f: F) -> Sub<E2>
+    where
+        F: Fn(E) -> E2 + 'static,
+        E: 'static,
+        E2: 'static,
+    {
+        let f = Rc::new(f);
+        Sub {
+            recipes: self
+                .recipes
+                .into_iter()
+                .map(|(id, start)| {
+                    let mut hasher = DefaultHasher::new();
+                    id.hash(&mut hasher);
+                    TypeId::of::<F>().hash(&mut hasher);
+
+                    let f = f.clone();
+                    let start: Start<E2> = Box::new(move |send: Rc<dyn Fn(E2)>| {
+                        start(Rc::new(move |msg| send(f(msg))))
+                    });
+                    (hasher.finish(), start)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<E> Default for Sub<E> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+/// Running subscriptions of an application.
+#[derive(Default)]
+#[cfg(any(feature = "web", feature = "native"))]
+pub(crate) struct Subscriptions {
+    active: HashMap<u64, Box<dyn FnOnce()>>,
+}
+
+#[cfg(any(feature = "web", feature = "native"))]
+impl Subscriptions {
+    /// Start new subscriptions in `sub` and stop the ones that are missing from it.
+    pub(crate) fn update<E>(&mut self, sub: Sub<E>, send: &Rc<dyn Fn(E)>) {
+        let mut active = HashMap::with_capacity(sub.recipes.len());
+        for (id, start) in sub.recipes {
+            if active.contains_key(&id) {
+                report_duplicate();
+                continue;
+            }
+
+            let stop = self
+                .active
+                .remove(&id)
+                .unwrap_or_else(|| start(send.clone()));
+            active.insert(id, stop);
+        }
+
+        for (_, stop) in self.active.drain() {
+            stop();
+        }
+        self.active = active;
+    }
+}
+
+#[cfg(any(feature = "web", feature = "native"))]
+impl Drop for Subscriptions {
+    fn drop(&mut self) {
+        for (_, stop) in self.active.drain() {
+            stop();
+        }
+    }
+}
+
+/// Report a subscription that is ignored because another has the same id in debug builds.
+#[cfg(any(feature = "web", feature = "native"))]
+fn report_duplicate() {
+    if cfg!(debug_assertions) {
+        let message = "duplicate subscription, use `Sub::key` to run subscriptions of the same \
+                       kind with the same parameters";
+
+        #[cfg(all(feature = "web", target_arch = "wasm32"))]
+        web_sys::console::warn_1(&message.into());
+
+        #[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+        eprintln!("warning: {}", message);
+    }
+}
@@ -0,0 +1,42 @@
+use super::{NodeId, NodeKind, TestPlatform};
+use crate::{Platform, View};
+use std::borrow::Cow;
+
+/// Create an element node with a child view.
+pub fn element<V>(tag: impl Into<Cow<'static, str>>, view: V) -> Element<V> {
+    Element {
+        tag: tag.into(),
+        view,
+    }
+}
+
+/// View for the [`element`] function.
+pub struct Element<V> {
+    tag: Cow<'static, str>,
+    view: V,
+}
+
+impl<E, V> View<TestPlatform<E>> for Element<V>
+where
+    V: View<TestPlatform<E>>,
+{
+    type State = (NodeId, V::State);
+
+    fn build(self, cx: &mut TestPlatform<E>) -> Self::State {
+        let node = cx.create(NodeKind::Element(self.tag));
+        cx.insert(node);
+
+        let view = cx.with_nested(node, |cx| self.view.build(cx));
+        (node, view)
+    }
+
+    fn rebuild(self, cx: &mut TestPlatform<E>, (node, view): &mut Self::State) {
+        cx.advance();
+        cx.with_nested(*node, |cx| self.view.rebuild(cx, view));
+    }
+
+    fn remove(cx: &mut TestPlatform<E>, (node, view): &mut Self::State) {
+        cx.with_nested(*node, |cx| V::remove(cx, view));
+        cx.detach(*node);
+    }
+}
@@ -0,0 +1,422 @@
+//! In-memory platform for testing views.
+//!
+//! [`TestPlatform`] keeps a tree of element and text nodes in memory and records every
+//! operation applied to it, so view trees can be built, rebuilt and inspected under
+//! plain `cargo test` without a browser or a window.
+
+use crate::{view::Contexts, Platform, View};
+use std::{borrow::Cow, fmt, marker::PhantomData, mem};
+
+mod element;
+pub use element::{element, Element};
+
+/// Identifier of a node in a [`TestPlatform`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+/// Content of a node in a [`TestPlatform`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NodeKind {
+    /// Element node with a tag name.
+    Element(Cow<'static, str>),
+
+    /// Text node.
+    Text(String),
+}
+
+/// Operation applied to the node tree of a [`TestPlatform`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Op {
+    /// A node was inserted into `parent` at `index`.
+    Insert {
+        parent: NodeId,
+        index: usize,
+        node: NodeId,
+    },
+
+    /// `len` children of `parent` were moved from index `from` to index `to`.
+    Move {
+        parent: NodeId,
+        from: usize,
+        len: usize,
+        to: usize,
+    },
+
+    /// The content of a node was updated.
+    Update { node: NodeId },
+
+    /// A node was removed from the tree.
+    Remove { node: NodeId },
+}
+
+struct Node {
+    kind: NodeKind,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/// In-memory testing platform.
+pub struct TestPlatform<E = ()> {
+    nodes: Vec<Node>,
+    stack: Vec<(NodeId, usize)>,
+    ops: Vec<Op>,
+    contexts: Contexts,
+    _marker: PhantomData<E>,
+}
+
+impl<E> Platform for TestPlatform<E> {
+    type Event = E;
+
+    fn advance(&mut self) {
+        let (_, idx) = self.stack.last_mut().unwrap();
+        *idx += 1;
+    }
+
+    fn index(&self) -> usize {
+        self.stack.last().unwrap().1
+    }
+
+    fn move_nodes(&mut self, from: usize, len: usize, to: usize) {
+        if from == to || len == 0 {
+            return;
+        }
+
+        let (parent, _) = *self.stack.last().unwrap();
+        let children = &mut self.nodes[parent.0].children;
+        let moved: Vec<_> = children.drain(from..from + len).collect();
+        children.splice(to..to, moved);
+
+        self.ops.push(Op::Move {
+            parent,
+            from,
+            len,
+            to,
+        });
+    }
+
+    fn contexts(&mut self) -> &mut Contexts {
+        &mut self.contexts
+    }
+}
+
+impl<E> Default for TestPlatform<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> TestPlatform<E> {
+    /// Create a new platform with an empty root node.
+    pub fn new() -> Self {
+        let root = Node {
+            kind: NodeKind::Element(Cow::Borrowed("root")),
+            parent: None,
+            children: Vec::new(),
+        };
+
+        Self {
+            nodes: vec![root],
+            stack: vec![(NodeId(0), 0)],
+            ops: Vec::new(),
+            contexts: Contexts::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Build a view under the root node.
+    pub fn build<V: View<Self>>(&mut self, view: V) -> V::State {
+        self.reset();
+        view.build(self)
+    }
+
+    /// Rebuild a view under the root node.
+    pub fn rebuild<V: View<Self>>(&mut self, view: V, state: &mut V::State) {
+        self.reset();
+        view.rebuild(self, state)
+    }
+
+    /// Remove a view from under the root node.
+    pub fn remove<V: View<Self>>(&mut self, state: &mut V::State) {
+        self.reset();
+        V::remove(self, state)
+    }
+
+    /// The root node that views are built into.
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    /// The content of a node.
+    pub fn kind(&self, node: NodeId) -> &NodeKind {
+        &self.nodes[node.0].kind
+    }
+
+    /// The parent of a node, or `None` if it is the root or has been removed.
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.nodes[node.0].parent
+    }
+
+    /// The children of a node in order.
+    pub fn children(&self, node: NodeId) -> &[NodeId] {
+        &self.nodes[node.0].children
+    }
+
+    /// The operations recorded since the last call to [`TestPlatform::take_ops`].
+    pub fn ops(&self) -> &[Op] {
+        &self.ops
+    }
+
+    /// Take the recorded operations, leaving the log empty.
+    pub fn take_ops(&mut self) -> Vec<Op> {
+        mem::take(&mut self.ops)
+    }
+
+    /// Create a detached node.
+    pub fn create(&mut self, kind: NodeKind) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            kind,
+            parent: None,
+            children: Vec::new(),
+        });
+        id
+    }
+
+    /// Insert a node at the current position.
+    pub fn insert(&mut self, node: NodeId) {
+        let (parent, idx) = self.stack.last_mut().unwrap();
+        let (parent, index) = (*parent, *idx);
+        *idx += 1;
+
+        let children = &mut self.nodes[parent.0].children;
+        assert!(
+            index <= children.len(),
+            "insert at {index} past the end of {} children",
+            children.len()
+        );
+        children.insert(index, node);
+        self.nodes[node.0].parent = Some(parent);
+
+        self.ops.push(Op::Insert {
+            parent,
+            index,
+            node,
+        });
+    }
+
+    /// Set the content of a text node.
+    pub fn update(&mut self, node: NodeId, text: impl Into<String>) {
+        self.nodes[node.0].kind = NodeKind::Text(text.into());
+        self.ops.push(Op::Update { node });
+    }
+
+    /// Detach a node from its parent.
+    pub fn detach(&mut self, node: NodeId) {
+        if let Some(parent) = self.nodes[node.0].parent.take() {
+            self.nodes[parent.0].children.retain(|child| *child != node);
+        }
+        self.ops.push(Op::Remove { node });
+    }
+
+    /// Run `f` with `node` as the current parent.
+    pub fn with_nested<R>(&mut self, node: NodeId, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.stack.push((node, 0));
+        let output = f(self);
+        self.stack.pop();
+        output
+    }
+
+    /// Run `f` with a context for views with a different message type.
+    pub fn with_event<E2, R>(&mut self, f: impl FnOnce(&mut TestPlatform<E2>) -> R) -> R {
+        let mut cx = TestPlatform {
+            nodes: mem::take(&mut self.nodes),
+            stack: mem::take(&mut self.stack),
+            ops: mem::take(&mut self.ops),
+            contexts: mem::take(&mut self.contexts),
+            _marker: PhantomData,
+        };
+        let output = f(&mut cx);
+
+        self.nodes = cx.nodes;
+        self.stack = cx.stack;
+        self.ops = cx.ops;
+        self.contexts = cx.contexts;
+        output
+    }
+
+    fn reset(&mut self) {
+        self.stack.truncate(1);
+        self.stack[0].1 = 0;
+    }
+
+    fn fmt_node(&self, node: NodeId, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind(node) {
+            NodeKind::Element(tag) => {
+                write!(f, "<{tag}>")?;
+                for child in self.children(node) {
+                    self.fmt_node(*child, f)?;
+                }
+                write!(f, "</{tag}>")
+            }
+            NodeKind::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+impl<E> fmt::Display for TestPlatform<E> {
+    /// Format the children of the root node as markup.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for child in self.children(self.root()) {
+            self.fmt_node(*child, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> View<TestPlatform<E>> for &'_ str {
+    type State = (Self, NodeId);
+
+    fn build(self, cx: &mut TestPlatform<E>) -> Self::State {
+        let node = cx.create(NodeKind::Text(self.to_owned()));
+        cx.insert(node);
+        (self, node)
+    }
+
+    fn rebuild(self, cx: &mut TestPlatform<E>, (prev, node): &mut Self::State) {
+        if self != *prev {
+            *prev = self;
+            cx.update(*node, self);
+        }
+        cx.advance()
+    }
+
+    fn remove(cx: &mut TestPlatform<E>, state: &mut Self::State) {
+        cx.detach(state.1);
+    }
+}
+
+impl<E> View<TestPlatform<E>> for String {
+    type State = (String, NodeId);
+
+    fn build(self, cx: &mut TestPlatform<E>) -> Self::State {
+        let node = cx.create(NodeKind::Text(self.clone()));
+        cx.insert(node);
+        (self, node)
+    }
+
+    fn rebuild(self, cx: &mut TestPlatform<E>, (prev, node): &mut Self::State) {
+        if self != *prev {
+            *prev = self;
+            cx.update(*node, prev.clone());
+        }
+        cx.advance()
+    }
+
+    fn remove(cx: &mut TestPlatform<E>, state: &mut Self::State) {
+        cx.detach(state.1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{element, Element, NodeId, Op, TestPlatform};
+    use crate::view::{lazy, Lazy};
+
+    #[test]
+    fn tuple() {
+        let mut cx = TestPlatform::<()>::new();
+        let mut state = cx.build((element("a", "1"), "2"));
+        assert_eq!(cx.to_string(), "<a>1</a>2");
+        cx.take_ops();
+
+        cx.rebuild((element("a", "3"), "2"), &mut state);
+        assert_eq!(cx.to_string(), "<a>3</a>2");
+        assert_eq!(cx.take_ops(), [Op::Update { node: NodeId(2) }]);
+
+        cx.remove::<(Element<&str>, &str)>(&mut state);
+        assert_eq!(cx.to_string(), "");
+        assert_eq!(
+            cx.take_ops(),
+            [
+                Op::Remove { node: NodeId(2) },
+                Op::Remove { node: NodeId(1) },
+                Op::Remove { node: NodeId(3) },
+            ]
+        );
+    }
+
+    #[test]
+    fn option() {
+        let mut cx = TestPlatform::<()>::new();
+        let mut state = cx.build(("a", None::<&str>, "c"));
+        assert_eq!(cx.to_string(), "ac");
+        cx.take_ops();
+
+        cx.rebuild(("a", Some("b"), "c"), &mut state);
+        assert_eq!(cx.to_string(), "abc");
+        assert_eq!(
+            cx.take_ops(),
+            [Op::Insert {
+                parent: cx.root(),
+                index: 1,
+                node: NodeId(3),
+            }]
+        );
+
+        cx.rebuild(("a", None::<&str>, "c"), &mut state);
+        assert_eq!(cx.to_string(), "ac");
+        assert_eq!(cx.take_ops(), [Op::Remove { node: NodeId(3) }]);
+    }
+
+    #[test]
+    fn keyed() {
+        let mut cx = TestPlatform::<()>::new();
+        let mut state = cx.build(vec![(1, "a"), (2, "b"), (3, "c")]);
+        assert_eq!(cx.to_string(), "abc");
+        cx.take_ops();
+
+        cx.rebuild(vec![(3, "c"), (1, "a"), (2, "b")], &mut state);
+        assert_eq!(cx.to_string(), "cab");
+        assert_eq!(
+            cx.take_ops(),
+            [Op::Move {
+                parent: cx.root(),
+                from: 2,
+                len: 1,
+                to: 0,
+            }]
+        );
+
+        cx.remove::<Vec<(i32, &str)>>(&mut state);
+        assert_eq!(cx.to_string(), "");
+    }
+
+    #[test]
+    fn lazy_rebuilds_on_change() {
+        fn view(input: u8, text: &'static str) -> Lazy<u8, &'static str> {
+            lazy(&input, text)
+        }
+
+        let mut cx = TestPlatform::<()>::new();
+        let mut state = cx.build((view(0, "a"), "b"));
+        assert_eq!(cx.to_string(), "ab");
+        cx.take_ops();
+
+        cx.rebuild((view(0, "c"), "b"), &mut state);
+        assert_eq!(cx.to_string(), "ab");
+        assert_eq!(cx.take_ops(), []);
+
+        cx.rebuild((view(1, "c"), "d"), &mut state);
+        assert_eq!(cx.to_string(), "cd");
+        assert_eq!(
+            cx.take_ops(),
+            [
+                Op::Update { node: NodeId(1) },
+                Op::Update { node: NodeId(2) },
+            ]
+        );
+
+        cx.remove::<(Lazy<u8, &str>, &str)>(&mut state);
+        assert_eq!(cx.to_string(), "");
+    }
+}
@@ -0,0 +1,74 @@
+use super::View;
+use crate::Platform;
+use std::any::{Any, TypeId};
+
+/// Object-safe, type-erased [`View`].
+///
+/// This is implemented for every `'static` view so it can be boxed as `Box<dyn AnyView<P>>`,
+/// which is itself a [`View`].
+pub trait AnyView<P: Platform> {
+    /// Build the initial type-erased state of the view.
+    fn dyn_build(self: Box<Self>, cx: &mut P) -> AnyState<P>;
+
+    /// Rebuild the view with its current type-erased state.
+    ///
+    /// If the state was built by a different type of view it is removed and rebuilt.
+    fn dyn_rebuild(self: Box<Self>, cx: &mut P, state: &mut AnyState<P>);
+}
+
+/// State of a type-erased view.
+pub struct AnyState<P> {
+    view_type: TypeId,
+    state: Box<dyn Any>,
+    remove: fn(&mut P, &mut dyn Any),
+}
+
+impl<P, V> AnyView<P> for V
+where
+    P: Platform,
+    V: View<P> + 'static,
+    V::State: 'static,
+{
+    fn dyn_build(self: Box<Self>, cx: &mut P) -> AnyState<P> {
+        AnyState {
+            view_type: TypeId::of::<V>(),
+            state: Box::new((*self).build(cx)),
+            remove: remove_any::<P, V>,
+        }
+    }
+
+    fn dyn_rebuild(self: Box<Self>, cx: &mut P, state: &mut AnyState<P>) {
+        if state.view_type == TypeId::of::<V>() {
+            let view_state = state.state.downcast_mut().unwrap();
+            (*self).rebuild(cx, view_state);
+        } else {
+            (state.remove)(cx, state.state.as_mut());
+            *state = self.dyn_build(cx);
+        }
+    }
+}
+
+fn remove_any<P, V>(cx: &mut P, state: &mut dyn Any)
+where
+    P: Platform,
+    V: View<P>,
+    V::State: 'static,
+{
+    V::remove(cx, state.downcast_mut().unwrap())
+}
+
+impl<P: Platform> View<P> for Box<dyn AnyView<P>> {
+    type State = AnyState<P>;
+
+    fn build(self, cx: &mut P) -> Self::State {
+        self.dyn_build(cx)
+    }
+
+    fn rebuild(self, cx: &mut P, state: &mut Self::State) {
+        self.dyn_rebuild(cx, state)
+    }
+
+    fn remove(cx: &mut P, state: &mut Self::State) {
+        (state.remove)(cx, state.state.as_mut())
+    }
+}
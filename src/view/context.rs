@@ -0,0 +1,153 @@
+use super::{Platform, View};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    marker::PhantomData,
+    rc::Rc,
+};
+
+/// Typed values provided to descendant views.
+#[derive(Clone, Default)]
+pub struct Contexts {
+    values: HashMap<TypeId, Vec<Rc<dyn Any>>>,
+    changed: usize,
+}
+
+impl Contexts {
+    /// Get the closest provided value of type `T`.
+    pub fn get<T: 'static>(&self) -> Option<Rc<T>> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|stack| stack.last())
+            .map(|value| value.clone().downcast().unwrap())
+    }
+
+    /// Returns `true` if a provided value has changed during the current rebuild.
+    ///
+    /// Views that skip rebuilding their children, like [`Lazy`](super::Lazy),
+    /// rebuild them anyway while this is set.
+    pub fn is_changed(&self) -> bool {
+        self.changed > 0
+    }
+
+    /// Clone the provided values without the changes of the current rebuild.
+    ///
+    /// This is for rebuilding part of a view later on, outside of its parent's rebuild.
+    pub fn snapshot(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            changed: 0,
+        }
+    }
+
+    fn push<T: 'static>(&mut self, value: Rc<T>) {
+        self.values
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(value);
+    }
+
+    fn pop<T: 'static>(&mut self) {
+        if let Some(stack) = self.values.get_mut(&TypeId::of::<T>()) {
+            stack.pop();
+        }
+    }
+}
+
+/// Provide a value to the descendants of a view.
+///
+/// Descendants can read the value with [`use_context`].
+pub fn provide<T, V>(value: T, view: V) -> Provide<T, V>
+where
+    T: PartialEq + 'static,
+{
+    Provide { value, view }
+}
+
+/// View for the [`provide`] function.
+pub struct Provide<T, V> {
+    value: T,
+    view: V,
+}
+
+impl<P, T, V> View<P> for Provide<T, V>
+where
+    T: PartialEq + 'static,
+    V: View<P>,
+    P: Platform,
+{
+    type State = (Rc<T>, V::State);
+
+    fn build(self, cx: &mut P) -> Self::State {
+        let value = Rc::new(self.value);
+
+        cx.contexts().push(value.clone());
+        let state = self.view.build(cx);
+        cx.contexts().pop::<T>();
+
+        (value, state)
+    }
+
+    fn rebuild(self, cx: &mut P, state: &mut Self::State) {
+        let is_changed = *state.0 != self.value;
+        if is_changed {
+            state.0 = Rc::new(self.value);
+            cx.contexts().changed += 1;
+        }
+
+        cx.contexts().push(state.0.clone());
+        self.view.rebuild(cx, &mut state.1);
+        cx.contexts().pop::<T>();
+
+        if is_changed {
+            cx.contexts().changed -= 1;
+        }
+    }
+
+    fn remove(cx: &mut P, state: &mut Self::State) {
+        V::remove(cx, &mut state.1)
+    }
+}
+
+/// Build a view from the closest value of type `T` provided by an ancestor,
+/// or `None` if there isn't one.
+pub fn use_context<T, F, V>(f: F) -> UseContext<T, F>
+where
+    T: 'static,
+    F: FnOnce(Option<&T>) -> V,
+{
+    UseContext {
+        f,
+        _marker: PhantomData,
+    }
+}
+
+/// View for the [`use_context`] function.
+pub struct UseContext<T, F> {
+    f: F,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<P, T, F, V> View<P> for UseContext<T, F>
+where
+    T: 'static,
+    F: FnOnce(Option<&T>) -> V,
+    V: View<P>,
+    P: Platform,
+{
+    type State = V::State;
+
+    fn build(self, cx: &mut P) -> Self::State {
+        let value = cx.contexts().get::<T>();
+        (self.f)(value.as_deref()).build(cx)
+    }
+
+    fn rebuild(self, cx: &mut P, state: &mut Self::State) {
+        let value = cx.contexts().get::<T>();
+        (self.f)(value.as_deref()).rebuild(cx, state)
+    }
+
+    fn remove(cx: &mut P, state: &mut Self::State) {
+        V::remove(cx, state)
+    }
+}
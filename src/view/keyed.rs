@@ -0,0 +1,332 @@
+use super::View;
+use crate::Platform;
+use std::{collections::HashMap, hash::Hash};
+
+/// Keyed list of views.
+///
+/// Children are matched to their previous state by key, so reordering the list moves
+/// the existing elements instead of rebuilding them in place.
+impl<P, K, V> View<P> for Vec<(K, V)>
+where
+    P: Platform,
+    K: Hash + Eq,
+    V: View<P>,
+{
+    /// The key, element count and state of each child view.
+    type State = Vec<(K, usize, V::State)>;
+
+    fn build(self, cx: &mut P) -> Self::State {
+        self.into_iter()
+            .map(|(key, view)| {
+                let start = cx.index();
+                let state = view.build(cx);
+                (key, cx.index() - start, state)
+            })
+            .collect()
+    }
+
+    fn rebuild(self, cx: &mut P, state: &mut Self::State) {
+        let start = cx.index();
+
+        // Find the previous position of each key
+        let mut indices: HashMap<&K, usize> = HashMap::with_capacity(state.len());
+        for (idx, (key, _, _)) in state.iter().enumerate() {
+            indices.entry(key).or_insert(idx);
+        }
+        let sources: Vec<_> = self.iter().map(|(key, _)| indices.remove(key)).collect();
+
+        // Remove views whose keys are gone
+        let mut is_kept = vec![false; state.len()];
+        for &source in sources.iter().flatten() {
+            is_kept[source] = true;
+        }
+        for (idx, (_, len, view_state)) in state.iter_mut().enumerate() {
+            if !is_kept[idx] {
+                V::remove(cx, view_state);
+                *len = 0;
+            }
+        }
+
+        // Move the kept views into their new order, leaving the longest run of views
+        // that are already in order untouched.
+        // Views are visited from last to first so each one is placed before its successor.
+        let is_stable = longest_increasing_subsequence(&sources, state.len());
+        let mut unplaced = Fenwick::new(state.len());
+        for (idx, (_, len, _)) in state.iter().enumerate() {
+            unplaced.add(idx, *len);
+        }
+
+        // Elements already placed, grouped by the stable view they end up in front of
+        let mut placed = Fenwick::new(state.len());
+
+        let mut anchor = start + unplaced.prefix(state.len());
+        let mut anchor_group = None;
+        for &source in sources.iter().rev().flatten() {
+            let len = state[source].1;
+            let from = start + unplaced.prefix(source) + placed.prefix(source);
+
+            let group = if is_stable[source] {
+                anchor = from;
+                Some(source)
+            } else {
+                let to = if from < anchor { anchor - len } else { anchor };
+                cx.move_nodes(from, len, to);
+                anchor = to;
+                anchor_group
+            };
+
+            unplaced.sub(source, len);
+            if let Some(group) = group {
+                placed.add(group, len);
+            }
+            anchor_group = group;
+        }
+
+        // Build new views and rebuild old views in order
+        let mut old: Vec<_> = state.drain(..).map(Some).collect();
+        *state = self
+            .into_iter()
+            .zip(sources)
+            .map(|((key, view), source)| {
+                let idx = cx.index();
+                let view_state = if let Some(source) = source {
+                    let (_, _, mut view_state) = old[source].take().unwrap();
+                    view.rebuild(cx, &mut view_state);
+                    view_state
+                } else {
+                    view.build(cx)
+                };
+                (key, cx.index() - idx, view_state)
+            })
+            .collect();
+    }
+
+    fn remove(cx: &mut P, state: &mut Self::State) {
+        for (_, _, view_state) in &mut state[..] {
+            V::remove(cx, view_state);
+        }
+    }
+}
+
+/// Find the longest increasing subsequence of previous indices.
+///
+/// Returns whether each previous index (out of `len`) is part of the subsequence.
+fn longest_increasing_subsequence(sources: &[Option<usize>], len: usize) -> Vec<bool> {
+    let sources: Vec<usize> = sources.iter().flatten().copied().collect();
+
+    // Position in `sources` of the smallest tail of each subsequence length
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors = vec![None; sources.len()];
+    for (pos, &source) in sources.iter().enumerate() {
+        let length = tails.partition_point(|&tail| sources[tail] < source);
+        if length > 0 {
+            predecessors[pos] = Some(tails[length - 1]);
+        }
+
+        if length == tails.len() {
+            tails.push(pos);
+        } else {
+            tails[length] = pos;
+        }
+    }
+
+    let mut is_stable = vec![false; len];
+    let mut pos = tails.last().copied();
+    while let Some(current) = pos {
+        is_stable[sources[current]] = true;
+        pos = predecessors[current];
+    }
+    is_stable
+}
+
+/// Binary indexed tree for prefix sums of element counts.
+struct Fenwick {
+    tree: Vec<usize>,
+}
+
+impl Fenwick {
+    fn new(len: usize) -> Self {
+        Self {
+            tree: vec![0; len + 1],
+        }
+    }
+
+    fn add(&mut self, idx: usize, value: usize) {
+        let mut idx = idx + 1;
+        while idx < self.tree.len() {
+            self.tree[idx] += value;
+            idx += idx & idx.wrapping_neg();
+        }
+    }
+
+    fn sub(&mut self, idx: usize, value: usize) {
+        let mut idx = idx + 1;
+        while idx < self.tree.len() {
+            self.tree[idx] -= value;
+            idx += idx & idx.wrapping_neg();
+        }
+    }
+
+    /// Sum of the values before `idx`.
+    fn prefix(&self, idx: usize) -> usize {
+        let mut idx = idx;
+        let mut sum = 0;
+        while idx > 0 {
+            sum += self.tree[idx];
+            idx -= idx & idx.wrapping_neg();
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{NodeId, Op, TestPlatform};
+    use std::collections::HashMap;
+
+    /// Keyed list where each child renders two text nodes.
+    fn view(keys: &[u32]) -> Vec<(u32, (String, &'static str))> {
+        keys.iter()
+            .map(|&key| (key, (key.to_string(), ",")))
+            .collect()
+    }
+
+    fn markup(keys: &[u32]) -> String {
+        keys.iter().map(|key| format!("{key},")).collect()
+    }
+
+    /// The nodes of each child, by key.
+    fn nodes(cx: &TestPlatform, keys: &[u32]) -> HashMap<u32, [NodeId; 2]> {
+        let children = cx.children(cx.root());
+        keys.iter()
+            .enumerate()
+            .map(|(idx, &key)| (key, [children[idx * 2], children[idx * 2 + 1]]))
+            .collect()
+    }
+
+    /// Rebuild from `old` to `new`, checking the output and that kept children aren't recreated.
+    fn check(old: &[u32], new: &[u32]) -> Vec<Op> {
+        let mut cx = TestPlatform::<()>::new();
+        let mut state = cx.build(view(old));
+        let old_nodes = nodes(&cx, old);
+        cx.take_ops();
+
+        cx.rebuild(view(new), &mut state);
+        assert_eq!(cx.to_string(), markup(new), "{old:?} -> {new:?}");
+
+        assert_kept(&old_nodes, &nodes(&cx, new), old, new);
+        cx.take_ops()
+    }
+
+    /// Check that children with a unique key in both lists keep their nodes.
+    fn assert_kept(
+        old_nodes: &HashMap<u32, [NodeId; 2]>,
+        new_nodes: &HashMap<u32, [NodeId; 2]>,
+        old: &[u32],
+        new: &[u32],
+    ) {
+        let is_unique = |keys: &[u32], key| keys.iter().filter(|k| **k == key).count() == 1;
+        for (key, old_node) in old_nodes {
+            if is_unique(old, *key) && is_unique(new, *key) {
+                assert_eq!(Some(old_node), new_nodes.get(key), "{old:?} -> {new:?}");
+            }
+        }
+    }
+
+    fn moves(ops: &[Op]) -> usize {
+        ops.iter()
+            .filter(|op| matches!(op, Op::Move { .. }))
+            .count()
+    }
+
+    #[test]
+    fn move_last_to_front() {
+        let ops = check(&[1, 2, 3, 4, 5], &[5, 1, 2, 3, 4]);
+        assert!(matches!(
+            ops[..],
+            [Op::Move {
+                from: 8,
+                len: 2,
+                to: 0,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn move_first_to_back() {
+        let ops = check(&[1, 2, 3, 4, 5], &[2, 3, 4, 5, 1]);
+        assert!(matches!(
+            ops[..],
+            [Op::Move {
+                from: 0,
+                len: 2,
+                to: 8,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn reverse() {
+        let ops = check(&[1, 2, 3, 4, 5], &[5, 4, 3, 2, 1]);
+        assert_eq!(moves(&ops), 4);
+    }
+
+    #[test]
+    fn swap() {
+        let ops = check(&[1, 2, 3, 4, 5], &[1, 4, 3, 2, 5]);
+        assert_eq!(moves(&ops), 2);
+    }
+
+    #[test]
+    fn remove_and_insert() {
+        let ops = check(&[1, 2, 3, 4], &[0, 2, 5, 4, 6]);
+        assert_eq!(moves(&ops), 0);
+
+        check(&[1, 2, 3], &[]);
+        check(&[], &[1, 2, 3]);
+        check(&[1, 2, 3, 4], &[4, 7, 1]);
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        check(&[1, 1, 2], &[2, 1, 1]);
+        check(&[1, 2, 1], &[1, 1, 1]);
+        check(&[1, 1], &[2]);
+    }
+
+    #[test]
+    fn random() {
+        // Deterministic xorshift generator
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move |max: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % max) as u32
+        };
+
+        let mut cx = TestPlatform::<()>::new();
+        let mut keys = Vec::new();
+        let mut state = cx.build(view(&keys));
+
+        for _ in 0..2000 {
+            let len = next(12);
+            let mut new_keys: Vec<u32> = (0..len).map(|_| next(16)).collect();
+            new_keys.dedup();
+
+            let old_nodes = nodes(&cx, &keys);
+            cx.rebuild(view(&new_keys), &mut state);
+            assert_eq!(
+                cx.to_string(),
+                markup(&new_keys),
+                "{keys:?} -> {new_keys:?}"
+            );
+
+            assert_kept(&old_nodes, &nodes(&cx, &new_keys), &keys, &new_keys);
+
+            keys = new_keys;
+        }
+    }
+}
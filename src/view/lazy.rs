@@ -7,21 +7,15 @@ use std::{
 
 /// Lazy-loaded constant view.
 /// The child view will never be rebuilt.
-pub fn once<V, P>(view: V) -> Lazy<(), V>
-where
-    V: View<P>,
-    P: Platform,
-{
+pub fn once<V>(view: V) -> Lazy<(), V> {
     lazy(&(), view)
 }
 
 /// Lazy-loaded view.
 /// The child view will only be rebuilt if the input has changed.
-pub fn lazy<T, V, P>(input: &T, view: V) -> Lazy<T, V>
+pub fn lazy<T, V>(input: &T, view: V) -> Lazy<T, V>
 where
     T: Hash,
-    V: View<P>,
-    P: Platform,
 {
     let mut hasher = DefaultHasher::new();
     input.hash(&mut hasher);
@@ -46,21 +40,31 @@ where
     V: View<P>,
     P: Platform,
 {
-    type State = (u64, V::State);
+    /// The input hash, element count and state of the child view.
+    type State = (u64, usize, V::State);
 
     fn build(self, cx: &mut P) -> Self::State {
+        let start = cx.index();
         let child_state = self.view.build(cx);
-        (self.hash, child_state)
+        (self.hash, cx.index() - start, child_state)
     }
 
     fn rebuild(self, cx: &mut P, state: &mut Self::State) {
-        if self.hash != state.0 {
+        if self.hash != state.0 || cx.contexts().is_changed() {
             state.0 = self.hash;
-            self.view.rebuild(cx, &mut state.1)
+
+            let start = cx.index();
+            self.view.rebuild(cx, &mut state.2);
+            state.1 = cx.index() - start;
+        } else {
+            // Skip over the elements of the unchanged child view
+            for _ in 0..state.1 {
+                cx.advance();
+            }
         }
     }
 
     fn remove(cx: &mut P, state: &mut Self::State) {
-        V::remove(cx, &mut state.1)
+        V::remove(cx, &mut state.2)
     }
 }
@@ -0,0 +1,134 @@
+use std::marker::PhantomData;
+
+#[cfg(any(feature = "web", feature = "native"))]
+use std::{cell::RefCell, rc::Rc};
+
+/// Map the messages of a child view to the parent's message type.
+///
+/// This lets a view built for a platform with its own message type, like `Web<ChildMsg>`,
+/// be embedded in a parent view like `Web<ParentMsg>`.
+pub fn map<V, F, E1, E2>(view: V, f: F) -> Map<V, F, E1>
+where
+    F: Fn(E1) -> E2 + 'static,
+{
+    Map {
+        view,
+        f,
+        _marker: PhantomData,
+    }
+}
+
+/// View for the [`map`] function.
+pub struct Map<V, F, E> {
+    view: V,
+    #[cfg_attr(not(any(feature = "web", feature = "native")), allow(dead_code))]
+    f: F,
+    _marker: PhantomData<fn(E)>,
+}
+
+impl<V, F, E1, E2> super::View<crate::testing::TestPlatform<E2>> for Map<V, F, E1>
+where
+    V: super::View<crate::testing::TestPlatform<E1>>,
+    F: Fn(E1) -> E2 + 'static,
+{
+    type State = V::State;
+
+    fn build(self, cx: &mut crate::testing::TestPlatform<E2>) -> Self::State {
+        cx.with_event(|cx| self.view.build(cx))
+    }
+
+    fn rebuild(self, cx: &mut crate::testing::TestPlatform<E2>, state: &mut Self::State) {
+        cx.with_event(|cx| self.view.rebuild(cx, state))
+    }
+
+    fn remove(cx: &mut crate::testing::TestPlatform<E2>, state: &mut Self::State) {
+        cx.with_event(|cx| V::remove(cx, state))
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl<V, F, E1, E2> super::View<crate::ssr::Ssr<E2>> for Map<V, F, E1>
+where
+    V: super::View<crate::ssr::Ssr<E1>>,
+    F: Fn(E1) -> E2 + 'static,
+{
+    type State = V::State;
+
+    fn build(self, cx: &mut crate::ssr::Ssr<E2>) -> Self::State {
+        cx.with_event(|cx| self.view.build(cx))
+    }
+
+    fn rebuild(self, cx: &mut crate::ssr::Ssr<E2>, state: &mut Self::State) {
+        cx.with_event(|cx| self.view.rebuild(cx, state))
+    }
+
+    fn remove(cx: &mut crate::ssr::Ssr<E2>, state: &mut Self::State) {
+        cx.with_event(|cx| V::remove(cx, state))
+    }
+}
+
+#[cfg(feature = "web")]
+impl<V, F, E1, E2> super::View<crate::web::Web<E2>> for Map<V, F, E1>
+where
+    V: super::View<crate::web::Web<E1>>,
+    F: Fn(E1) -> E2 + 'static,
+    E1: 'static,
+    E2: 'static,
+{
+    type State = (Rc<RefCell<F>>, crate::web::UpdateFn<E1>, V::State);
+
+    fn build(self, cx: &mut crate::web::Web<E2>) -> Self::State {
+        let f = Rc::new(RefCell::new(self.f));
+        let parent = cx.update.clone();
+        let map_f = f.clone();
+        let update: crate::web::UpdateFn<E1> = Rc::new(RefCell::new(Some(Rc::new(move |msg| {
+            let msg = (map_f.borrow())(msg);
+            crate::web::dispatch(&parent, msg);
+        }))));
+
+        let state = cx.with_update(update.clone(), |cx| self.view.build(cx));
+        (f, update, state)
+    }
+
+    fn rebuild(self, cx: &mut crate::web::Web<E2>, (f, update, state): &mut Self::State) {
+        *f.borrow_mut() = self.f;
+        cx.with_update(update.clone(), |cx| self.view.rebuild(cx, state));
+    }
+
+    fn remove(cx: &mut crate::web::Web<E2>, (_, update, state): &mut Self::State) {
+        cx.with_update(update.clone(), |cx| V::remove(cx, state));
+    }
+}
+
+#[cfg(feature = "native")]
+impl<V, F, E1, E2> super::View<crate::native::Native<E2>> for Map<V, F, E1>
+where
+    V: super::View<crate::native::Native<E1>>,
+    F: Fn(E1) -> E2 + 'static,
+    E1: 'static,
+    E2: 'static,
+{
+    type State = (Rc<RefCell<F>>, Rc<dyn Fn(E1)>, V::State);
+
+    fn build(self, cx: &mut crate::native::Native<E2>) -> Self::State {
+        let f = Rc::new(RefCell::new(self.f));
+        let parent = cx.update.clone();
+        let map_f = f.clone();
+        let update: Rc<dyn Fn(E1)> = Rc::new(move |msg| {
+            let msg = (map_f.borrow())(msg);
+            parent(msg)
+        });
+
+        let state = cx.with_update(update.clone(), |cx| self.view.build(cx));
+        (f, update, state)
+    }
+
+    fn rebuild(self, cx: &mut crate::native::Native<E2>, (f, update, state): &mut Self::State) {
+        *f.borrow_mut() = self.f;
+        cx.with_update(update.clone(), |cx| self.view.rebuild(cx, state));
+    }
+
+    fn remove(cx: &mut crate::native::Native<E2>, (_, update, state): &mut Self::State) {
+        cx.with_update(update.clone(), |cx| V::remove(cx, state));
+    }
+}
@@ -0,0 +1,64 @@
+use super::{Platform, View};
+
+/// Memoized view.
+/// The child view will only be rebuilt if the input is not equal to the previous input.
+///
+/// Unlike [`lazy`](super::lazy), this stores the input itself and compares it with
+/// [`PartialEq`], so it can't skip a rebuild because of a hash collision.
+pub fn memo<T, V>(input: T, view: V) -> Memo<T, V, fn(&T, &T) -> bool>
+where
+    T: PartialEq,
+{
+    memo_with(input, T::eq, view)
+}
+
+/// Memoized view with a custom comparison.
+/// The child view will only be rebuilt if `eq` returns `false` for the previous and new input.
+pub fn memo_with<T, F, V>(input: T, eq: F, view: V) -> Memo<T, V, F>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    Memo { input, eq, view }
+}
+
+/// View for the [`memo`] and [`memo_with`] functions.
+pub struct Memo<T, V, F> {
+    input: T,
+    eq: F,
+    view: V,
+}
+
+impl<P, T, V, F> View<P> for Memo<T, V, F>
+where
+    F: Fn(&T, &T) -> bool,
+    V: View<P>,
+    P: Platform,
+{
+    /// The previous input, element count and state of the child view.
+    type State = (T, usize, V::State);
+
+    fn build(self, cx: &mut P) -> Self::State {
+        let start = cx.index();
+        let child_state = self.view.build(cx);
+        (self.input, cx.index() - start, child_state)
+    }
+
+    fn rebuild(self, cx: &mut P, state: &mut Self::State) {
+        if !(self.eq)(&state.0, &self.input) || cx.contexts().is_changed() {
+            state.0 = self.input;
+
+            let start = cx.index();
+            self.view.rebuild(cx, &mut state.2);
+            state.1 = cx.index() - start;
+        } else {
+            // Skip over the elements of the unchanged child view
+            for _ in 0..state.1 {
+                cx.advance();
+            }
+        }
+    }
+
+    fn remove(cx: &mut P, state: &mut Self::State) {
+        V::remove(cx, &mut state.2)
+    }
+}
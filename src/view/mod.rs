@@ -1,10 +1,27 @@
-///! Viewable components
+//! Viewable components
 use crate::Platform;
 use impl_trait_for_tuples::impl_for_tuples;
 
+mod any;
+pub use any::{AnyState, AnyView};
+
+mod context;
+pub use context::{provide, use_context, Contexts, Provide, UseContext};
+
+mod keyed;
+
 mod lazy;
 pub use lazy::{lazy, once, Lazy};
 
+mod map;
+pub use map::{map, Map};
+
+mod memo;
+pub use memo::{memo, memo_with, Memo};
+
+mod one_of;
+pub use one_of::{Either, OneOf3, OneOf4, OneOf5, OneOf6, OneOf7, OneOf8};
+
 /// Viewable user interface component.
 ///
 /// Composing views creates a statically-typed UI tree that can be traversed to display elements.
@@ -47,18 +64,17 @@ where
         } else if let Some(s) = state {
             V::remove(cx, s);
             *state = None;
-            cx.advance();
         }
     }
 
     fn remove(cx: &mut P, state: &mut Self::State) {
         if let Some(state) = state {
             V::remove(cx, state);
-            cx.advance()
         }
     }
 }
 
+#[allow(clippy::unused_unit)]
 #[impl_for_tuples(16)]
 impl<P: Platform> View<P> for Tuple {
     for_tuples!( type State = ( #( Tuple::State ),* ); );
@@ -76,71 +92,24 @@ impl<P: Platform> View<P> for Tuple {
     }
 }
 
-impl<P, K, V> View<P> for Vec<(K, V)>
-where
-    P: Platform,
-    K: PartialEq,
-    V: View<P>,
-{
-    type State = Vec<(K, V::State)>;
-
-    fn build(self, cx: &mut P) -> Self::State {
-        self.into_iter()
-            .map(|(key, view)| {
-                let state = view.build(cx);
-                (key, state)
-            })
-            .collect()
-    }
-
-    fn rebuild(self, cx: &mut P, state: &mut Self::State) {
-        // Build new views and rebuild old views
-        let new_state = self
-            .into_iter()
-            .map(|(key, view)| {
-                let view_state = if let Some(pos) = state
-                    .iter_mut()
-                    .position(|(state_key, _)| &key == state_key)
-                {
-                    let (_, mut view_state) = state.remove(pos);
-                    view.rebuild(cx, &mut view_state);
-                    view_state
-                } else {
-                    view.build(cx)
-                };
-                (key, view_state)
-            })
-            .collect();
-
-        // Remove trailing views
-        <Self as View<P>>::remove(cx, state);
-
-        // Update the state
-        *state = new_state;
-    }
-
-    fn remove(cx: &mut P, state: &mut Self::State) {
-        for (_, view_state) in &mut state[..] {
-            V::remove(cx, view_state);
-        }
-    }
-}
-
 #[cfg(feature = "web")]
 impl<E> View<crate::web::Web<E>> for &'_ str {
     type State = (Self, web_sys::Text);
 
     fn build(self, cx: &mut crate::web::Web<E>) -> Self::State {
-        let elem = cx.document.create_text_node(&self);
-        cx.insert(&elem);
+        let elem = cx.hydrate_text(self).unwrap_or_else(|| {
+            let elem = cx.document.create_text_node(self);
+            cx.insert(&elem);
+            elem
+        });
 
         (self, elem)
     }
 
     fn rebuild(self, cx: &mut crate::web::Web<E>, (prev, text): &mut Self::State) {
-        if &self != &*prev {
+        if self != *prev {
             *prev = self;
-            text.set_text_content(Some(&self))
+            text.set_text_content(Some(self))
         }
         cx.advance()
     }
@@ -155,15 +124,18 @@ impl<E> View<crate::web::Web<E>> for String {
     type State = (String, web_sys::Text);
 
     fn build(self, cx: &mut crate::web::Web<E>) -> Self::State {
-        let elem = cx.document.create_text_node(&self);
-        cx.insert(&elem);
+        let elem = cx.hydrate_text(&self).unwrap_or_else(|| {
+            let elem = cx.document.create_text_node(&self);
+            cx.insert(&elem);
+            elem
+        });
         (self, elem)
     }
 
     fn rebuild(self, cx: &mut crate::web::Web<E>, (prev, text): &mut Self::State) {
-        if &self != &*prev {
+        if self != *prev {
             *prev = self;
-            text.set_text_content(Some(&prev))
+            text.set_text_content(Some(prev))
         }
         cx.advance()
     }
@@ -0,0 +1,87 @@
+use super::View;
+use crate::Platform;
+
+macro_rules! one_of {
+    ($(#[$attr:meta])* $name:ident, $($variant:ident: $ty:ident),+) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        pub enum $name<$($ty),+> {
+            $($variant($ty)),+
+        }
+
+        impl<P, $($ty),+> View<P> for $name<$($ty),+>
+        where
+            P: Platform,
+            $($ty: View<P>),+
+        {
+            type State = $name<$(<$ty as View<P>>::State),+>;
+
+            fn build(self, cx: &mut P) -> Self::State {
+                match self {
+                    $(Self::$variant(view) => $name::$variant(view.build(cx))),+
+                }
+            }
+
+            fn rebuild(self, cx: &mut P, state: &mut Self::State) {
+                match self {
+                    $(
+                        Self::$variant(view) => match state {
+                            $name::$variant(view_state) => view.rebuild(cx, view_state),
+                            _ => {
+                                // Replace the previous branch
+                                <Self as View<P>>::remove(cx, state);
+                                *state = $name::$variant(view.build(cx));
+                            }
+                        },
+                    )+
+                }
+            }
+
+            fn remove(cx: &mut P, state: &mut Self::State) {
+                match state {
+                    $($name::$variant(view_state) => $ty::remove(cx, view_state)),+
+                }
+            }
+        }
+    };
+}
+
+one_of!(
+    /// View of one of two branches.
+    ///
+    /// Switching branches removes the previous view and builds the new one,
+    /// otherwise the current branch is rebuilt in place.
+    Either,
+    Left: L,
+    Right: R
+);
+
+one_of!(
+    /// View of one of three branches.
+    OneOf3, A: A, B: B, C: C
+);
+
+one_of!(
+    /// View of one of four branches.
+    OneOf4, A: A, B: B, C: C, D: D
+);
+
+one_of!(
+    /// View of one of five branches.
+    OneOf5, A: A, B: B, C: C, D: D, E: E
+);
+
+one_of!(
+    /// View of one of six branches.
+    OneOf6, A: A, B: B, C: C, D: D, E: E, F: F
+);
+
+one_of!(
+    /// View of one of seven branches.
+    OneOf7, A: A, B: B, C: C, D: D, E: E, F: F, G: G
+);
+
+one_of!(
+    /// View of one of eight branches.
+    OneOf8, A: A, B: B, C: C, D: D, E: E, F: F, G: G, H: H
+);
@@ -0,0 +1,272 @@
+use super::{Task, TaskSender, Web};
+use crate::{sub::Subscriptions, Command, Sub, View};
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    rc::Rc,
+};
+use wasm_bindgen::{prelude::Closure, JsCast};
+
+/// How a web application schedules updates after a message is sent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Schedule {
+    /// Queue messages and handle them together, rebuilding the view once per animation frame.
+    #[default]
+    AnimationFrame,
+
+    /// Handle messages and rebuild the view as soon as they are sent.
+    ///
+    /// Messages sent while an update is already running are queued and handled
+    /// before it finishes.
+    Sync,
+}
+
+/// Handle to a running web application, returned from [`run`](super::run),
+/// [`run_in`](super::run_in) and [`hydrate`](super::hydrate).
+pub struct App<E> {
+    runtime: Rc<dyn AnyRuntime<E>>,
+}
+
+impl<E> App<E> {
+    /// Send a message to the application from outside of its views, such as from JavaScript.
+    pub fn dispatch(&self, msg: E) {
+        self.runtime.send(msg)
+    }
+
+    /// Set how the application schedules updates.
+    pub fn set_schedule(&self, schedule: Schedule) {
+        self.runtime.set_schedule(schedule)
+    }
+
+    /// Remove the application's views and stop its subscriptions.
+    ///
+    /// Event listeners are dropped with their views, and messages sent afterwards are ignored.
+    /// If this is called during an update, like from an event handler while the view is being
+    /// rebuilt, the application is unmounted after the current update.
+    pub fn unmount(self) {
+        self.runtime.unmount()
+    }
+}
+
+trait AnyRuntime<E> {
+    fn send(&self, msg: E);
+
+    fn set_schedule(&self, schedule: Schedule);
+
+    fn unmount(&self);
+}
+
+/// Message or task waiting to be handled by a [`Runtime`].
+enum Queued<E> {
+    Message(E),
+    Task(Task),
+}
+
+type UpdateBox<T, E> = Box<dyn Fn(&mut T, E) -> Command<E>>;
+
+type SubscriptionsBox<T, E> = Box<dyn Fn(&T) -> Sub<E>>;
+
+struct Runtime<T, E, V: View<Web<E>>> {
+    cx: RefCell<Web<E>>,
+    state: RefCell<T>,
+    view_state: RefCell<Option<V::State>>,
+    update: UpdateBox<T, E>,
+    view: Box<dyn Fn(&T) -> V>,
+    subscriptions: SubscriptionsBox<T, E>,
+    subs: RefCell<Subscriptions>,
+    queue: RefCell<VecDeque<Queued<E>>>,
+    schedule: Cell<Schedule>,
+    is_scheduled: Cell<bool>,
+    is_running: Cell<bool>,
+    is_unmounted: Cell<bool>,
+}
+
+impl<T, E, V> Runtime<T, E, V>
+where
+    T: 'static,
+    E: 'static,
+    V: View<Web<E>> + 'static,
+    V::State: 'static,
+{
+    /// Function that sends messages to this runtime.
+    fn sender(self: &Rc<Self>) -> Rc<dyn Fn(E)> {
+        let runtime = self.clone();
+        Rc::new(move |msg| runtime.send(msg))
+    }
+
+    /// Function that queues tasks with this runtime.
+    fn task_sender(self: &Rc<Self>) -> TaskSender {
+        let runtime = self.clone();
+        Rc::new(move |task| runtime.push(Queued::Task(task)))
+    }
+
+    fn send(self: &Rc<Self>, msg: E) {
+        self.push(Queued::Message(msg))
+    }
+
+    fn push(self: &Rc<Self>, queued: Queued<E>) {
+        if self.is_unmounted.get() {
+            return;
+        }
+        self.queue.borrow_mut().push_back(queued);
+
+        match self.schedule.get() {
+            Schedule::AnimationFrame => {
+                if !self.is_scheduled.replace(true) {
+                    let runtime = self.clone();
+                    let closure = Closure::once_into_js(move || {
+                        runtime.is_scheduled.set(false);
+                        runtime.flush();
+                    });
+                    web_sys::window()
+                        .unwrap()
+                        .request_animation_frame(closure.unchecked_ref())
+                        .unwrap();
+                }
+            }
+            Schedule::Sync => self.flush(),
+        }
+    }
+
+    /// Remove the view and stop handling messages.
+    fn remove(&self) {
+        self.is_unmounted.set(true);
+        self.queue.borrow_mut().clear();
+
+        let cx = &mut *self.cx.borrow_mut();
+        cx.stack.last_mut().unwrap().1 = 0;
+        if let Some(mut view_state) = self.view_state.borrow_mut().take() {
+            V::remove(cx, &mut view_state);
+        }
+
+        // Stop the subscriptions and drop the update function, which owns this runtime
+        *self.subs.borrow_mut() = Subscriptions::default();
+        cx.update.borrow_mut().take();
+        cx.tasks = None;
+    }
+
+    /// Handle the queued messages and tasks, then rebuild the view if the state changed.
+    fn flush(self: &Rc<Self>) {
+        // Messages sent during an update are handled by the update that is already running
+        if self.is_running.replace(true) {
+            return;
+        }
+
+        while !self.queue.borrow().is_empty() && !self.is_unmounted.get() {
+            let mut is_changed = false;
+            loop {
+                let Some(queued) = self.queue.borrow_mut().pop_front() else {
+                    break;
+                };
+
+                match queued {
+                    Queued::Message(msg) => {
+                        let command = (self.update)(&mut self.state.borrow_mut(), msg);
+                        command.run(self.sender());
+                        is_changed = true;
+                    }
+                    Queued::Task(task) => task(),
+                }
+            }
+
+            // `update` may have unmounted the application and stopped its subscriptions
+            if self.is_unmounted.get() {
+                break;
+            }
+
+            // Tasks like component updates rebuild their own views
+            if !is_changed {
+                continue;
+            }
+
+            let sub = (self.subscriptions)(&self.state.borrow());
+            self.subs.borrow_mut().update(sub, &self.sender());
+
+            let view = (self.view)(&self.state.borrow());
+            let cx = &mut *self.cx.borrow_mut();
+            cx.stack.last_mut().unwrap().1 = 0;
+            if let Some(view_state) = self.view_state.borrow_mut().as_mut() {
+                view.rebuild(cx, view_state);
+            }
+
+            // Messages sent while rebuilding have scheduled the next frame
+            if self.schedule.get() == Schedule::AnimationFrame {
+                break;
+            }
+        }
+
+        self.is_running.set(false);
+    }
+}
+
+impl<T, E, V> AnyRuntime<E> for Rc<Runtime<T, E, V>>
+where
+    T: 'static,
+    E: 'static,
+    V: View<Web<E>> + 'static,
+    V::State: 'static,
+{
+    fn send(&self, msg: E) {
+        Runtime::send(self, msg)
+    }
+
+    fn set_schedule(&self, schedule: Schedule) {
+        self.schedule.set(schedule)
+    }
+
+    fn unmount(&self) {
+        // Queued so the view is never removed while it's being rebuilt
+        let runtime = self.clone();
+        self.push(Queued::Task(Box::new(move || runtime.remove())));
+        self.flush();
+    }
+}
+
+/// Build the view in `cx` and start handling messages.
+pub(super) fn start<T, E, V, C>(
+    cx: Web<E>,
+    state: T,
+    update: impl Fn(&mut T, E) -> C + 'static,
+    view: impl Fn(&T) -> V + 'static,
+    subscriptions: impl Fn(&T) -> Sub<E> + 'static,
+    is_hydrating: bool,
+) -> App<E>
+where
+    T: 'static,
+    E: 'static,
+    V: View<Web<E>> + 'static,
+    V::State: 'static,
+    C: Into<Command<E>>,
+{
+    let runtime = Rc::new(Runtime {
+        cx: RefCell::new(cx),
+        state: RefCell::new(state),
+        view_state: RefCell::new(None),
+        update: Box::new(move |state, msg| update(state, msg).into()),
+        view: Box::new(view),
+        subscriptions: Box::new(subscriptions),
+        subs: RefCell::default(),
+        queue: RefCell::default(),
+        schedule: Cell::default(),
+        is_scheduled: Cell::new(false),
+        is_running: Cell::new(false),
+        is_unmounted: Cell::new(false),
+    });
+    *runtime.cx.borrow().update.borrow_mut() = Some(runtime.sender());
+    runtime.cx.borrow_mut().tasks = Some(runtime.task_sender());
+
+    let sub = (runtime.subscriptions)(&runtime.state.borrow());
+    runtime.subs.borrow_mut().update(sub, &runtime.sender());
+
+    let view = (runtime.view)(&runtime.state.borrow());
+    {
+        let cx = &mut *runtime.cx.borrow_mut();
+        cx.is_hydrating = is_hydrating;
+        *runtime.view_state.borrow_mut() = Some(view.build(cx));
+        cx.is_hydrating = false;
+    }
+
+    App {
+        runtime: Rc::new(runtime),
+    }
+}
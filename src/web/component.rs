@@ -0,0 +1,181 @@
+use super::{dispatch, Delegation, UpdateFn, Web};
+use crate::{view::Contexts, Platform, View};
+use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use web_sys::{Document, Element, Node};
+
+/// Component view with its own local state.
+///
+/// The state is created with `init` when the component is first built and is kept
+/// across rebuilds. Messages of type `M` emitted by the component's view are handled by
+/// `update` without touching the application's model, after which the component's view
+/// is rebuilt in place. Returning `Some` from `update` sends a message to the parent.
+///
+/// Messages are queued with the application's other messages, so a component is never
+/// updated while its view is being rebuilt.
+///
+/// Because local updates rebuild the component on its own, its view should keep the same
+/// number of top-level nodes, such as a single [`Html`](super::Html) element.
+pub fn component<I, U, F, S, M, E, V>(init: I, update: U, view: F) -> Component<I, U, F, M>
+where
+    I: FnOnce() -> S,
+    U: Fn(&mut S, M) -> Option<E> + 'static,
+    F: Fn(&S) -> V + 'static,
+    V: View<Web<M>>,
+{
+    Component {
+        init,
+        update,
+        view,
+        _marker: PhantomData,
+    }
+}
+
+/// View for the [`component`] function.
+pub struct Component<I, U, F, M> {
+    init: I,
+    update: U,
+    view: F,
+    _marker: PhantomData<fn(M)>,
+}
+
+/// State for the [`Component`] view.
+pub struct ComponentState<S, U, F, V> {
+    state: S,
+    update: U,
+    view: F,
+    view_state: V,
+    document: Document,
+    contexts: Contexts,
+    delegation: Option<Rc<Delegation>>,
+    parent: Element,
+    /// First node of the view, used to find its position when it's rebuilt on its own.
+    anchor: Option<Node>,
+}
+
+impl<I, U, F, S, M, E, V> View<Web<E>> for Component<I, U, F, M>
+where
+    I: FnOnce() -> S,
+    U: Fn(&mut S, M) -> Option<E> + 'static,
+    F: Fn(&S) -> V + 'static,
+    V: View<Web<M>>,
+    S: 'static,
+    M: 'static,
+    E: 'static,
+    V::State: 'static,
+{
+    type State = (
+        Rc<RefCell<ComponentState<S, U, F, V::State>>>,
+        UpdateFn<M>,
+    );
+
+    fn build(self, cx: &mut Web<E>) -> Self::State {
+        let (parent, idx) = cx.stack.last().cloned().unwrap();
+
+        let state = (self.init)();
+        let update: UpdateFn<M> = Rc::new(RefCell::new(None));
+        let view_state = cx.with_update(update.clone(), |cx| (self.view)(&state).build(cx));
+        let anchor = first_node(&parent, idx, cx.index());
+
+        let inner = Rc::new(RefCell::new(ComponentState {
+            state,
+            update: self.update,
+            view: self.view,
+            view_state,
+            document: cx.document.clone(),
+            contexts: cx.contexts().snapshot(),
+            delegation: cx.delegation.clone(),
+            parent,
+            anchor,
+        }));
+
+        let weak_inner = Rc::downgrade(&inner);
+        let weak_update = Rc::downgrade(&update);
+        let parent_update = cx.update.clone();
+        let tasks = cx.tasks.clone();
+        *update.borrow_mut() = Some(Rc::new(move |msg| {
+            let nested_tasks = tasks.clone();
+            let weak_inner = weak_inner.clone();
+            let weak_update = weak_update.clone();
+            let parent_update = parent_update.clone();
+            let task = move || {
+                let (Some(inner), Some(update)) = (weak_inner.upgrade(), weak_update.upgrade())
+                else {
+                    return;
+                };
+
+                let output = {
+                    let inner = &mut *inner.borrow_mut();
+                    let output = (inner.update)(&mut inner.state, msg);
+
+                    // Rebuild the component's view at the current position of its first node,
+                    // since views in front of it may have changed while it was skipped
+                    let idx = match &inner.anchor {
+                        Some(anchor) => position(&inner.parent, anchor),
+                        // A view without nodes has nothing to be positioned
+                        None => inner.parent.child_nodes().length() as _,
+                    };
+                    let mut cx = Web {
+                        document: inner.document.clone(),
+                        stack: vec![(inner.parent.clone(), idx)],
+                        contexts: inner.contexts.clone(),
+                        is_hydrating: false,
+                        delegation: inner.delegation.clone(),
+                        tasks: nested_tasks,
+                        update,
+                    };
+                    (inner.view)(&inner.state).rebuild(&mut cx, &mut inner.view_state);
+                    inner.anchor = first_node(&inner.parent, idx, cx.index());
+                    output
+                };
+
+                if let Some(msg) = output {
+                    dispatch(&parent_update, msg);
+                }
+            };
+
+            // Without a running application the update can't be queued
+            match &tasks {
+                Some(tasks) => tasks(Box::new(task)),
+                None => task(),
+            }
+        }));
+
+        (inner, update)
+    }
+
+    fn rebuild(self, cx: &mut Web<E>, (inner, update): &mut Self::State) {
+        let inner = &mut *inner.borrow_mut();
+        let (parent, idx) = cx.stack.last().cloned().unwrap();
+        inner.contexts = cx.contexts().snapshot();
+        inner.delegation = cx.delegation.clone();
+        inner.update = self.update;
+        inner.view = self.view;
+
+        let view = (inner.view)(&inner.state);
+        cx.with_update(update.clone(), |cx| view.rebuild(cx, &mut inner.view_state));
+        inner.anchor = first_node(&parent, idx, cx.index());
+        inner.parent = parent;
+    }
+
+    fn remove(cx: &mut Web<E>, (inner, update): &mut Self::State) {
+        let inner = &mut *inner.borrow_mut();
+        cx.with_update(update.clone(), |cx| V::remove(cx, &mut inner.view_state));
+    }
+}
+
+/// The first of the nodes from `start` to `end` in `parent`, or `None` if there are none.
+fn first_node(parent: &Element, start: usize, end: usize) -> Option<Node> {
+    if end > start {
+        parent.child_nodes().get(start as _)
+    } else {
+        None
+    }
+}
+
+/// The index of `node` in the children of `parent`.
+fn position(parent: &Element, node: &Node) -> usize {
+    let children = parent.child_nodes();
+    (0..children.length())
+        .position(|idx| children.get(idx).as_ref() == Some(node))
+        .expect("component node was removed from its parent")
+}
@@ -0,0 +1,284 @@
+use super::Web;
+use crate::View;
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{Element, Event, Node};
+
+type Handler = Rc<dyn Fn(Event)>;
+
+/// Handlers of each element, with their key and event name.
+type Handlers = HashMap<u32, Vec<(u64, Cow<'static, str>, Handler)>>;
+
+type Listeners = HashMap<Cow<'static, str>, Closure<dyn FnMut(Event)>>;
+
+/// Events that don't bubble, which the root can only receive while capturing.
+const NON_BUBBLING_EVENTS: &[&str] = &[
+    "abort",
+    "blur",
+    "canplay",
+    "canplaythrough",
+    "durationchange",
+    "emptied",
+    "ended",
+    "error",
+    "focus",
+    "invalid",
+    "load",
+    "loadeddata",
+    "loadedmetadata",
+    "loadstart",
+    "mouseenter",
+    "mouseleave",
+    "pause",
+    "play",
+    "playing",
+    "pointerenter",
+    "pointerleave",
+    "progress",
+    "ratechange",
+    "scroll",
+    "scrollend",
+    "seeked",
+    "seeking",
+    "stalled",
+    "suspend",
+    "timeupdate",
+    "toggle",
+    "volumechange",
+    "waiting",
+];
+
+/// Returns `true` if the root listens for `name` events while capturing.
+fn is_captured(name: &str) -> bool {
+    NON_BUBBLING_EVENTS.contains(&name)
+}
+
+/// Delegate the event handlers of a view to its parent element.
+///
+/// Instead of adding a listener to every element, [`On`](super::On) modifiers inside `view`
+/// register their handlers with the parent, which listens once for each event type.
+/// When an event bubbles up to the parent, handlers are called from the target up to the
+/// parent, stopping early if a handler calls `stop_propagation`.
+/// Events that don't bubble, like `focus`, only call the handlers of their target.
+///
+/// Handlers are called from the parent's listener, so `Event::current_target`
+/// is the parent instead of the handler's element.
+pub fn delegate<V>(view: V) -> Delegate<V> {
+    Delegate { view }
+}
+
+/// View for the [`delegate`] function.
+pub struct Delegate<V> {
+    view: V,
+}
+
+impl<E, V> View<Web<E>> for Delegate<V>
+where
+    V: View<Web<E>>,
+{
+    type State = (Rc<Delegation>, V::State);
+
+    fn build(self, cx: &mut Web<E>) -> Self::State {
+        let (root, _) = cx.stack.last().unwrap();
+        let delegation = Rc::new(Delegation::new(root.clone()));
+
+        let state = with_delegation(cx, &delegation, |cx| self.view.build(cx));
+        (delegation, state)
+    }
+
+    fn rebuild(self, cx: &mut Web<E>, (delegation, state): &mut Self::State) {
+        with_delegation(cx, delegation, |cx| self.view.rebuild(cx, state))
+    }
+
+    fn remove(cx: &mut Web<E>, (delegation, state): &mut Self::State) {
+        with_delegation(cx, delegation, |cx| V::remove(cx, state))
+    }
+}
+
+fn with_delegation<E, R>(
+    cx: &mut Web<E>,
+    delegation: &Rc<Delegation>,
+    f: impl FnOnce(&mut Web<E>) -> R,
+) -> R {
+    let prev = cx.delegation.replace(delegation.clone());
+    let output = f(cx);
+    cx.delegation = prev;
+    output
+}
+
+/// Registry of delegated event handlers for a root element.
+pub struct Delegation {
+    root: Element,
+    ids: js_sys::WeakMap,
+    next_id: Cell<u32>,
+    next_key: Cell<u64>,
+    handlers: RefCell<Handlers>,
+    listeners: RefCell<Listeners>,
+}
+
+impl Delegation {
+    fn new(root: Element) -> Self {
+        Self {
+            root,
+            ids: js_sys::WeakMap::new(),
+            next_id: Cell::new(0),
+            next_key: Cell::new(0),
+            handlers: RefCell::default(),
+            listeners: RefCell::default(),
+        }
+    }
+
+    /// Register a handler for `name` events on `element`.
+    ///
+    /// The handler is removed when the returned [`Registration`] is dropped.
+    pub(crate) fn register(
+        self: &Rc<Self>,
+        element: &Element,
+        name: Cow<'static, str>,
+        handler: Handler,
+    ) -> Registration {
+        let id = match self.ids.get(element).as_f64() {
+            Some(id) => id as u32,
+            None => {
+                let id = self.next_id.get();
+                self.next_id.set(id + 1);
+                self.ids.set(element, &id.into());
+                id
+            }
+        };
+
+        let key = self.next_key.get();
+        self.next_key.set(key + 1);
+
+        self.listen(name.clone());
+        self.handlers
+            .borrow_mut()
+            .entry(id)
+            .or_default()
+            .push((key, name, handler));
+
+        Registration {
+            delegation: Rc::downgrade(self),
+            id,
+            key,
+        }
+    }
+
+    /// Add a listener to the root for `name` events, if there isn't one already.
+    fn listen(self: &Rc<Self>, name: Cow<'static, str>) {
+        let mut listeners = self.listeners.borrow_mut();
+        if listeners.contains_key(&name) {
+            return;
+        }
+
+        let weak = Rc::downgrade(self);
+        let closure: Closure<dyn FnMut(Event)> = Closure::new(move |event: Event| {
+            if let Some(delegation) = weak.upgrade() {
+                delegation.dispatch(&event);
+            }
+        });
+
+        // Listen while bubbling so handlers run in the same order as listeners on each
+        // element would, capturing only the events that would never reach the root
+        self.root
+            .add_event_listener_with_callback_and_bool(
+                &name,
+                closure.as_ref().unchecked_ref(),
+                is_captured(&name),
+            )
+            .unwrap();
+        listeners.insert(name, closure);
+    }
+
+    /// Call the handlers for `event` from its target up to the root.
+    fn dispatch(&self, event: &Event) {
+        let name = event.type_();
+        let mut node = event
+            .target()
+            .and_then(|target| target.dyn_into::<Node>().ok());
+
+        while let Some(current) = node {
+            if let Some(id) = self.ids.get(&current).as_f64() {
+                // Clone the handlers so they can be changed while being called
+                let handlers: Vec<_> = self
+                    .handlers
+                    .borrow()
+                    .get(&(id as u32))
+                    .into_iter()
+                    .flatten()
+                    .filter(|(_, handler_name, _)| *handler_name == name)
+                    .map(|(_, _, handler)| handler.clone())
+                    .collect();
+
+                for handler in handlers {
+                    handler(event.clone());
+                }
+            }
+
+            if !event.bubbles() || event.cancel_bubble() || current == *self.root {
+                break;
+            }
+            node = current.parent_node();
+        }
+    }
+}
+
+impl Drop for Delegation {
+    fn drop(&mut self) {
+        for (name, closure) in self.listeners.get_mut().drain() {
+            self.root
+                .remove_event_listener_with_callback_and_bool(
+                    &name,
+                    closure.as_ref().unchecked_ref(),
+                    is_captured(&name),
+                )
+                .unwrap();
+        }
+    }
+}
+
+/// Handler registered with a [`Delegation`], which is removed when this is dropped.
+pub(crate) struct Registration {
+    delegation: Weak<Delegation>,
+    id: u32,
+    key: u64,
+}
+
+impl Registration {
+    /// Move the handler to events called `name`.
+    pub(crate) fn rename(&self, name: Cow<'static, str>) {
+        let Some(delegation) = self.delegation.upgrade() else {
+            return;
+        };
+
+        delegation.listen(name.clone());
+
+        let mut handlers = delegation.handlers.borrow_mut();
+        if let Some(handlers) = handlers.get_mut(&self.id) {
+            for (_, handler_name, _) in handlers.iter_mut().filter(|(key, ..)| *key == self.key) {
+                *handler_name = name.clone();
+            }
+        }
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        let Some(delegation) = self.delegation.upgrade() else {
+            return;
+        };
+
+        let mut handlers = delegation.handlers.borrow_mut();
+        if let Some(element_handlers) = handlers.get_mut(&self.id) {
+            element_handlers.retain(|(key, _, _)| *key != self.key);
+            if element_handlers.is_empty() {
+                handlers.remove(&self.id);
+            }
+        }
+    }
+}
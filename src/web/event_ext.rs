@@ -1,21 +1,47 @@
 use wasm_bindgen::JsCast;
-use web_sys::{Event, KeyboardEvent};
+use web_sys::{Event, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement, KeyboardEvent};
 
+/// Helpers for reading common values from events.
+///
+/// Each method returns `None` if the event or its target isn't the expected type.
 pub trait EventExt {
-    fn target_value(&self) -> String;
+    /// The target of this event as a `T`.
+    fn target_as<T: JsCast>(&self) -> Option<T>;
 
-    fn key_code(&self) -> u32;
+    /// The value of the `input`, `textarea` or `select` element that sent this event.
+    fn target_value(&self) -> Option<String>;
+
+    /// Whether the `input` element that sent this event is checked.
+    fn target_checked(&self) -> Option<bool>;
+
+    /// The key code of a keyboard event.
+    fn key_code(&self) -> Option<u32>;
 }
 
 impl EventExt for Event {
-    fn target_value(&self) -> String {
-        self.target()
-            .unwrap()
-            .unchecked_into::<web_sys::HtmlInputElement>()
-            .value()
+    fn target_as<T: JsCast>(&self) -> Option<T> {
+        self.target()?.dyn_into().ok()
+    }
+
+    fn target_value(&self) -> Option<String> {
+        let target = self.target()?;
+        if let Some(input) = target.dyn_ref::<HtmlInputElement>() {
+            Some(input.value())
+        } else if let Some(textarea) = target.dyn_ref::<HtmlTextAreaElement>() {
+            Some(textarea.value())
+        } else {
+            target
+                .dyn_ref::<HtmlSelectElement>()
+                .map(HtmlSelectElement::value)
+        }
+    }
+
+    fn target_checked(&self) -> Option<bool> {
+        self.target_as::<HtmlInputElement>()
+            .map(|input| input.checked())
     }
 
-    fn key_code(&self) -> u32 {
-        self.unchecked_ref::<KeyboardEvent>().key_code()
+    fn key_code(&self) -> Option<u32> {
+        self.dyn_ref::<KeyboardEvent>().map(KeyboardEvent::key_code)
     }
 }
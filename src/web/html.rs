@@ -1,163 +1,63 @@
-use super::{
-    attr::{attr, Attr},
-    class, on, value, On, Value, Web,
+use super::{prop, NodeRef, Prop, Web};
+use crate::{
+    html::{Attr, ClassIf, Classes, Html, Style, Value},
+    view::View,
+    Modify, Platform,
 };
-use crate::{view::View, Modify, Platform};
-use std::{borrow::Cow, marker::PhantomData};
-use web_sys::{Element, Event};
+use std::borrow::Cow;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, HtmlElement, HtmlInputElement};
 
-pub struct ClassList {
-    string: Option<String>,
-    is_empty: bool,
-}
-
-impl Default for ClassList {
-    fn default() -> Self {
-        Self {
-            string: Some(String::new()),
-            is_empty: true,
-        }
-    }
-}
-
-impl ClassList {
-    pub fn class(&mut self, class_name: impl AsRef<str>)  -> &mut Self{
-        if self.is_empty {
-            self.is_empty = false;
-        } else if let Some(s) = self.string.as_mut() {
-            s.push(' ');
-        }
-
-        if let Some(s) = self.string.as_mut() {
-            s.push_str(class_name.as_ref());
-        }
-
-        self
-    }
-
-    pub fn build(&mut self) -> String {
-        self.string.take().unwrap()
-    }
-}
-
-impl From<ClassList> for Cow<'static, str> {
-    fn from(mut value: ClassList) -> Self {
-        value.build().into()
-    }
-}
-
-/// State for the [`Html`] view.
-pub struct State<M, V> {
+/// State for the [`Html`] view on the web.
+pub struct HtmlState<M, V> {
     element: Element,
     modify: M,
     view: V,
 }
 
-/// Html element view.
-pub struct Html<A, V, E> {
-    tag: Cow<'static, str>,
-    modify: A,
-    view: V,
-    _marker: PhantomData<E>,
-}
-
-impl<E> Html<(), (), E> {
-    pub fn new(tag: impl Into<Cow<'static, str>>) -> Self {
-        Self {
-            tag: tag.into(),
-            modify: (),
-            view: (),
-            _marker: PhantomData,
-        }
-    }
-}
-
 impl<A, V, E> Html<A, V, E> {
-    pub fn modify<A2>(self, modify: A2) -> Html<(A, A2), V, E> {
-        Html {
-            tag: self.tag,
-            modify: (self.modify, modify),
-            view: self.view,
-            _marker: PhantomData,
-        }
-    }
-
-    pub fn on<F>(self, name: impl Into<Cow<'static, str>>, handler: F) -> Html<(A, On<F>), V, E>
-    where
-        F: FnMut(Event) -> E + 'static,
-        E: 'static,
-    {
-        self.modify(on(name, handler))
-    }
-
-    pub fn attr(
+    pub fn prop(
         self,
         name: impl Into<Cow<'static, str>>,
-        value: impl Into<Cow<'static, str>>,
-    ) -> Html<(A, Attr), V, E> {
-        self.modify(attr(name, value))
-    }
-
-    pub fn class(self, value: impl Into<Cow<'static, str>>) -> Html<(A, Attr), V, E> {
-        self.modify(class(value))
-    }
-
-    pub fn value(self, value: impl Into<Cow<'static, str>>) -> Html<(A, Value), V, E> {
-        self.modify(value::value(value))
+        value: impl Into<JsValue>,
+    ) -> Html<(A, Prop), V, E> {
+        self.modify(prop(name, value))
     }
 
-    pub fn view<V2>(self, view: V2) -> Html<A, (V, V2), E>
-    where
-        V2: View<Web<E>>,
-    {
-        Html {
-            tag: self.tag,
-            modify: self.modify,
-            view: (self.view, view),
-            _marker: PhantomData,
-        }
+    /// Set `node_ref` to this element while it's built.
+    pub fn node_ref(self, node_ref: &NodeRef) -> Html<(A, NodeRef), V, E> {
+        self.modify(node_ref.clone())
     }
 }
 
-macro_rules! html_tags {
-    ($($tag:ident),+) => {
-        $(
-            pub fn $tag() -> Self {
-                Html::new(stringify!($tag))
-            }
-        )+
-    };
-}
-
-impl<E> Html<(), (), E> {
-    html_tags!(
-        a, abbr, address, area, article, aside, audio, b, base, bdi, bdo, blockquote, body, br,
-        button, canvas, caption, cite, code, col, colgroup, data, datalist, dd, del, details, dfn,
-        dialog, div, dl, dt, em, embed, fieldset, figcaption, figure, footer, form, h1, h2, h3, h4,
-        h5, h6, head, header, hgroup, hr, html, i, iframe, img, input, ins, kbd, label, legend, li,
-        link, main, map, mark, meta, meter, nav, noscript, object, ol, optgroup, option, output, p,
-        param, picture, pre, progress, q, rp, rt, ruby, s, samp, script, section, select, small,
-        source, span, strong, style, sub, summary, sup, table, tbody, td, template, textarea,
-        tfoot, th, thead, time, title, tr, track, u, ul, var, video, wbr
-    );
-}
-
 impl<A, V, E> View<Web<E>> for Html<A, V, E>
 where
     A: Modify<Web<E>, Element>,
     V: View<Web<E>>,
     E: 'static,
 {
-    type State = State<A::State, V::State>;
+    type State = HtmlState<A::State, V::State>;
 
     fn build(self, cx: &mut Web<E>) -> Self::State {
-        let mut element = cx.document.create_element(&self.tag).unwrap();
-        cx.insert(&element);
+        let mut element = cx.hydrate_element(&self.tag).unwrap_or_else(|| {
+            let namespace = self
+                .namespace
+                .map(Cow::into_owned)
+                .or_else(|| cx.namespace());
+            let element = match namespace {
+                Some(namespace) => cx.document.create_element_ns(Some(&namespace), &self.tag),
+                None => cx.document.create_element(&self.tag),
+            }
+            .unwrap();
+
+            cx.insert(&element);
+            element
+        });
 
         let modify = self.modify.build(cx, &mut element);
         let (element, _, view) = cx.with_nested(element, |cx| self.view.build(cx));
 
-        State {
+        HtmlState {
             element,
             modify,
             view,
@@ -182,3 +82,138 @@ where
         state.element.remove();
     }
 }
+
+impl<E> Modify<Web<E>, Element> for Attr {
+    /// The previous name and value of the attribute.
+    type State = (Cow<'static, str>, Option<Cow<'static, str>>);
+
+    fn build(self, cx: &mut Web<E>, elem: &mut Element) -> Self::State {
+        if let Some(value) = &self.value {
+            elem.set_attribute(&self.name, value).unwrap();
+        } else if cx.is_hydrating() {
+            elem.remove_attribute(&self.name).unwrap();
+        }
+
+        (self.name, self.value)
+    }
+
+    fn rebuild(self, _cx: &mut Web<E>, elem: &mut Element, state: &mut Self::State) {
+        if self.name != state.0 {
+            elem.remove_attribute(&state.0).unwrap();
+            state.1 = None;
+        }
+
+        if self.value != state.1 {
+            if let Some(value) = &self.value {
+                elem.set_attribute(&self.name, value).unwrap();
+            } else {
+                elem.remove_attribute(&self.name).unwrap();
+            }
+        }
+
+        *state = (self.name, self.value);
+    }
+}
+
+impl<E> Modify<Web<E>, Element> for Classes {
+    /// The previous classes.
+    type State = Vec<Cow<'static, str>>;
+
+    fn build(self, _cx: &mut Web<E>, elem: &mut Element) -> Self::State {
+        let class_list = elem.class_list();
+        for class in &self.classes {
+            class_list.add_1(class).unwrap();
+        }
+        self.classes
+    }
+
+    fn rebuild(self, _cx: &mut Web<E>, elem: &mut Element, state: &mut Self::State) {
+        if self.classes == *state {
+            return;
+        }
+
+        let class_list = elem.class_list();
+        for class in state.iter().filter(|class| !self.classes.contains(class)) {
+            class_list.remove_1(class).unwrap();
+        }
+        for class in self.classes.iter().filter(|class| !state.contains(class)) {
+            class_list.add_1(class).unwrap();
+        }
+        *state = self.classes;
+    }
+}
+
+impl<E> Modify<Web<E>, Element> for ClassIf {
+    /// The previous name and condition.
+    type State = (Cow<'static, str>, bool);
+
+    fn build(self, cx: &mut Web<E>, elem: &mut Element) -> Self::State {
+        if self.condition {
+            elem.class_list().add_1(&self.name).unwrap();
+        } else if cx.is_hydrating() {
+            elem.class_list().remove_1(&self.name).unwrap();
+        }
+        (self.name, self.condition)
+    }
+
+    fn rebuild(self, _cx: &mut Web<E>, elem: &mut Element, state: &mut Self::State) {
+        if self.name == state.0 && self.condition == state.1 {
+            return;
+        }
+
+        let class_list = elem.class_list();
+        if state.1 {
+            class_list.remove_1(&state.0).unwrap();
+        }
+        if self.condition {
+            class_list.add_1(&self.name).unwrap();
+        }
+        *state = (self.name, self.condition);
+    }
+}
+
+impl<E> Modify<Web<E>, Element> for Style {
+    /// The previous property and value.
+    type State = (Cow<'static, str>, Option<Cow<'static, str>>);
+
+    fn build(self, _cx: &mut Web<E>, elem: &mut Element) -> Self::State {
+        if let Some(value) = &self.value {
+            let style = elem.unchecked_ref::<HtmlElement>().style();
+            style.set_property(&self.property, value).unwrap();
+        }
+
+        (self.property, self.value)
+    }
+
+    fn rebuild(self, _cx: &mut Web<E>, elem: &mut Element, state: &mut Self::State) {
+        if self.property == state.0 && self.value == state.1 {
+            return;
+        }
+
+        let style = elem.unchecked_ref::<HtmlElement>().style();
+        if self.property != state.0 {
+            style.remove_property(&state.0).unwrap();
+        }
+        if let Some(value) = &self.value {
+            style.set_property(&self.property, value).unwrap();
+        } else {
+            style.remove_property(&self.property).unwrap();
+        }
+
+        *state = (self.property, self.value);
+    }
+}
+
+impl<E> Modify<Web<E>, Element> for Value {
+    type State = ();
+
+    fn build(self, _cx: &mut Web<E>, elem: &mut Element) -> Self::State {
+        elem.unchecked_ref::<HtmlInputElement>()
+            .set_value(self.value.as_ref());
+    }
+
+    fn rebuild(self, _cx: &mut Web<E>, elem: &mut Element, _state: &mut Self::State) {
+        elem.unchecked_ref::<HtmlInputElement>()
+            .set_value(self.value.as_ref());
+    }
+}
@@ -1,28 +1,74 @@
-use crate::view::View;
-use crate::Platform;
-use std::{cell::RefCell, rc::Rc};
+//! Web platform that builds views as DOM nodes.
+//!
+//! The [`Html`] view and its modifiers are re-exported from [`html`](crate::html).
+
+use crate::view::{Contexts, View};
+use crate::{Command, Platform, Sub};
+use std::{cell::RefCell, future::Future, mem, rc::Rc};
+use wasm_bindgen::JsCast;
 pub use web_sys::Element;
-use web_sys::{Document, Node};
+use web_sys::{Document, Node, Text};
+
+pub use crate::html::*;
+
+mod app;
+pub use app::{App, Schedule};
+
+mod component;
+pub use component::{component, Component, ComponentState};
+
+mod delegate;
+pub use delegate::{delegate, Delegate, Delegation};
 
 mod event_ext;
 pub use event_ext::EventExt;
 
-pub mod html;
-pub use self::html::{Html, ClassList};
+mod html;
+pub use html::HtmlState;
 
 mod on;
-pub use self::on::{on, On};
+pub use on::OnState;
+
+mod node_ref;
+pub use node_ref::{NodeRef, NodeRefState};
+
+mod portal;
+pub use portal::{portal, Portal, PortalState};
+
+mod sub;
+pub use sub::{animation_frame, interval, on_key_down, on_resize, on_window};
+
+mod prop;
+pub use prop::{prop, Prop};
+
+/// Shared handle to the message update function.
+pub type UpdateFn<E> = Rc<RefCell<Option<Rc<dyn Fn(E)>>>>;
 
-mod value;
-pub use self::value::{value, Value};
+/// Send a message to an update function, ignoring it if there is none.
+///
+/// The function is cloned out of its cell before being called,
+/// so it can send more messages to the same cell.
+pub(crate) fn dispatch<E>(update: &UpdateFn<E>, msg: E) {
+    let update_fn = update.borrow().clone();
+    if let Some(update_fn) = update_fn {
+        update_fn(msg)
+    }
+}
+
+/// Work queued to run after the current update, such as a component's local update.
+pub(crate) type Task = Box<dyn FnOnce()>;
 
-mod attr;
-pub use attr::{attr, class};
+/// Function that queues a [`Task`] with the running application.
+pub(crate) type TaskSender = Rc<dyn Fn(Task)>;
 
 pub struct Web<E> {
     pub document: Document,
     stack: Vec<(web_sys::Element, usize)>,
-    pub update: Rc<RefCell<Option<Box<dyn FnMut(E)>>>>,
+    contexts: Contexts,
+    is_hydrating: bool,
+    delegation: Option<Rc<Delegation>>,
+    tasks: Option<TaskSender>,
+    pub update: UpdateFn<E>,
 }
 
 impl<E> Platform for Web<E> {
@@ -32,6 +78,39 @@ impl<E> Platform for Web<E> {
         let (_, idx) = self.stack.last_mut().unwrap();
         *idx += 1;
     }
+
+    fn index(&self) -> usize {
+        self.stack.last().unwrap().1
+    }
+
+    fn move_nodes(&mut self, from: usize, len: usize, to: usize) {
+        if from == to || len == 0 {
+            return;
+        }
+
+        let (parent, _) = self.stack.last().unwrap();
+        let children = parent.child_nodes();
+        let nodes: Vec<_> = (from..from + len)
+            .map(|idx| children.get(idx as _).unwrap())
+            .collect();
+
+        // `to` excludes the moved nodes, so shift it past them when moving forward
+        let reference_idx = if to < from { to } else { to + len };
+        let reference = children.get(reference_idx as _);
+        for node in &nodes {
+            parent.insert_before(node, reference.as_ref()).unwrap();
+        }
+    }
+
+    fn contexts(&mut self) -> &mut Contexts {
+        &mut self.contexts
+    }
+}
+
+impl<E> Default for Web<E> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<E> Web<E> {
@@ -40,22 +119,166 @@ impl<E> Web<E> {
         let document = window.document().expect("should have a document on window");
         let body = document.body().expect("HTML document missing body");
 
+        Self::new_in(body.into())
+    }
+
+    /// Create a context that builds views into `root` instead of the body.
+    pub fn new_in(root: Element) -> Self {
         Self {
-            document,
-            stack: vec![(body.into(), 0)],
+            document: root
+                .owner_document()
+                .expect("root element missing document"),
+            stack: vec![(root, 0)],
+            contexts: Contexts::default(),
+            is_hydrating: false,
+            delegation: None,
+            tasks: None,
             update: Rc::new(RefCell::new(None)),
         }
     }
 
     pub fn insert(&mut self, node: &Node) {
         let (parent, idx) = self.stack.last_mut().unwrap();
+        // Count every child node, since text views are siblings of elements
         parent
-            .insert_before(node, parent.children().get_with_index(*idx as _).as_deref())
+            .insert_before(node, parent.child_nodes().get(*idx as _).as_ref())
             .unwrap();
 
         *idx += 1;
     }
 
+    /// The namespace URI that new elements inherit from the current parent.
+    ///
+    /// HTML inside of an SVG `foreignObject` element doesn't inherit its namespace.
+    pub fn namespace(&self) -> Option<String> {
+        let (parent, _) = self.stack.last().unwrap();
+        if parent.local_name() == "foreignObject" {
+            None
+        } else {
+            parent.namespace_uri()
+        }
+    }
+
+    /// Returns `true` if views are adopting existing nodes instead of creating them.
+    pub fn is_hydrating(&self) -> bool {
+        self.is_hydrating
+    }
+
+    /// Adopt the existing element at the current position while hydrating.
+    ///
+    /// Returns `None` if this isn't hydrating or the existing node isn't a `tag` element,
+    /// in which case the caller should create and insert a new element.
+    /// A mismatched element is removed, and other nodes in front of the element,
+    /// like whitespace between tags in the server markup, are removed before adopting it.
+    pub fn hydrate_element(&mut self, tag: &str) -> Option<Element> {
+        if !self.is_hydrating {
+            return None;
+        }
+
+        let (parent, idx) = self.stack.last_mut().unwrap();
+        loop {
+            let Some(node) = parent.child_nodes().get(*idx as _) else {
+                report_mismatch(&format!("expected <{}>, found nothing", tag));
+                return None;
+            };
+
+            let node = match node.dyn_into::<Element>() {
+                Ok(element) if element.local_name() == tag => {
+                    *idx += 1;
+                    return Some(element);
+                }
+                Ok(element) => {
+                    report_mismatch(&format!(
+                        "expected <{}>, found <{}>",
+                        tag,
+                        element.local_name()
+                    ));
+                    element.remove();
+                    return None;
+                }
+                Err(node) => node,
+            };
+
+            let is_whitespace = node
+                .dyn_ref::<Text>()
+                .is_some_and(|text| text.data().trim().is_empty());
+            if !is_whitespace {
+                report_mismatch(&format!("expected <{}>, found {:?}", tag, node.node_name()));
+            }
+            parent.remove_child(&node).unwrap();
+        }
+    }
+
+    /// Adopt the existing text node at the current position while hydrating.
+    ///
+    /// Adjacent text is merged into one node when markup is parsed,
+    /// so a text node starting with `text` is split to adopt only its start.
+    /// Returns `None` if this isn't hydrating or there is no text node to adopt.
+    pub fn hydrate_text(&mut self, text: &str) -> Option<Text> {
+        // Empty text isn't rendered to markup
+        if !self.is_hydrating || text.is_empty() {
+            return None;
+        }
+
+        let (parent, idx) = self.stack.last_mut().unwrap();
+        let Some(node) = parent.child_nodes().get(*idx as _) else {
+            report_mismatch(&format!("expected text {:?}, found nothing", text));
+            return None;
+        };
+        let Ok(node) = node.dyn_into::<Text>() else {
+            report_mismatch(&format!("expected text {:?}, found an element", text));
+            return None;
+        };
+
+        let data = node.data();
+        if data != text {
+            if data.starts_with(text) {
+                node.split_text(text.encode_utf16().count() as _).unwrap();
+            } else {
+                report_mismatch(&format!("expected text {:?}, found {:?}", text, data));
+                node.set_data(text);
+            }
+        }
+
+        *idx += 1;
+        Some(node)
+    }
+
+    /// Spawn a future on the current thread and send its output as a message.
+    ///
+    /// The message is handled by the same update function used by event handlers.
+    pub fn spawn(&self, future: impl Future<Output = E> + 'static)
+    where
+        E: 'static,
+    {
+        let update_cell = self.update.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            dispatch(&update_cell, future.await);
+        });
+    }
+
+    /// Run `f` with a context that sends its messages through `update`.
+    pub fn with_update<E2, R>(
+        &mut self,
+        update: UpdateFn<E2>,
+        f: impl FnOnce(&mut Web<E2>) -> R,
+    ) -> R {
+        let mut cx = Web {
+            document: self.document.clone(),
+            stack: mem::take(&mut self.stack),
+            contexts: mem::take(&mut self.contexts),
+            is_hydrating: self.is_hydrating,
+            delegation: self.delegation.clone(),
+            tasks: self.tasks.clone(),
+            update,
+        };
+        let output = f(&mut cx);
+
+        self.stack = cx.stack;
+        self.contexts = cx.contexts;
+        output
+    }
+
     pub fn with_nested<R>(
         &mut self,
         elem: Element,
@@ -64,37 +287,100 @@ impl<E> Web<E> {
         self.stack.push((elem, 0));
         let output = f(self);
         let (elem, count) = self.stack.pop().unwrap();
+
+        if self.is_hydrating {
+            // Remove existing nodes that weren't adopted by a view
+            let nodes = elem.child_nodes();
+            while let Some(node) = nodes.get(count as _) {
+                report_mismatch(&format!("unexpected {:?}", node.node_name()));
+                elem.remove_child(&node).unwrap();
+            }
+        }
+
         (elem, count, output)
     }
 }
 
-pub fn run<T, E, V>(state: T, update: impl Fn(&mut T, E) + 'static, f: impl Fn(&T) -> V + 'static)
+/// Report a difference between existing markup and the view being hydrated in debug builds.
+fn report_mismatch(message: &str) {
+    if cfg!(debug_assertions) {
+        web_sys::console::warn_1(&format!("hydration mismatch: {}", message).into());
+    }
+}
+
+/// Command that runs a future on the current thread and sends its output as a message.
+pub fn perform<E: 'static>(future: impl Future<Output = E> + 'static) -> Command<E> {
+    Command::effect(move |send| {
+        wasm_bindgen_futures::spawn_local(async move { send(future.await) })
+    })
+}
+
+/// Run a web application.
+///
+/// Messages emitted by the view are handled by `update`, which may return a [`Command`]
+/// whose messages are handled in turn before the view is rebuilt.
+/// Messages are queued and handled together once per animation frame
+/// (see [`Schedule`]).
+/// After each update the [`Sub`] returned from `subscriptions` is diffed against the
+/// running subscriptions.
+///
+/// The application is mounted to the document's body and keeps running until the page is
+/// closed, even if the returned [`App`] is dropped.
+/// Use [`run_in`] to mount it somewhere else.
+pub fn run<T, E, V, C>(
+    state: T,
+    update: impl Fn(&mut T, E) -> C + 'static,
+    f: impl Fn(&T) -> V + 'static,
+    subscriptions: impl Fn(&T) -> Sub<E> + 'static,
+) -> App<E>
 where
     T: 'static,
     E: 'static,
-    V: View<Web<E>>,
+    V: View<Web<E>> + 'static,
     V::State: 'static,
+    C: Into<Command<E>>,
 {
-    let f = Rc::new(f);
-
-    let state = Rc::new(RefCell::new(state));
-    let view_state: Rc<RefCell<Option<V::State>>> = Rc::new(RefCell::new(None));
-
-    let cx_state = state.clone();
-    let cx_f = f.clone();
-    let cx_view_state = view_state.clone();
-
-    let cx = Rc::new(RefCell::new(Web::new()));
-    let update_cx = cx.clone();
-    *cx.borrow_mut().update.borrow_mut() = Some(Box::new(move |msg| {
-        update(&mut cx_state.borrow_mut(), msg);
+    app::start(Web::new(), state, update, f, subscriptions, false)
+}
 
-        let view = cx_f(&cx_state.borrow());
-        let update_cx = &mut update_cx.borrow_mut();
-        update_cx.stack.last_mut().unwrap().1 = 0;
-        view.rebuild(update_cx, cx_view_state.borrow_mut().as_mut().unwrap());
-    }));
+/// Run a web application inside `root`, returning a handle to it.
+///
+/// This works like [`run`], except views are built as children of `root`.
+/// Dropping the returned [`App`] leaves the application running.
+pub fn run_in<T, E, V, C>(
+    root: Element,
+    state: T,
+    update: impl Fn(&mut T, E) -> C + 'static,
+    f: impl Fn(&T) -> V + 'static,
+    subscriptions: impl Fn(&T) -> Sub<E> + 'static,
+) -> App<E>
+where
+    T: 'static,
+    E: 'static,
+    V: View<Web<E>> + 'static,
+    V::State: 'static,
+    C: Into<Command<E>>,
+{
+    app::start(Web::new_in(root), state, update, f, subscriptions, false)
+}
 
-    let view = f(&state.borrow());
-    *view_state.borrow_mut() = Some(view.build(&mut cx.borrow_mut()));
+/// Run a web application on markup rendered by [`Ssr`](crate::ssr::Ssr).
+///
+/// This works like [`run`], except the first build adopts the existing nodes in the body
+/// instead of creating new ones. Differences between the markup and the view
+/// are fixed and, in debug builds, reported to the console.
+pub fn hydrate<T, E, V, C>(
+    state: T,
+    update: impl Fn(&mut T, E) -> C + 'static,
+    f: impl Fn(&T) -> V + 'static,
+    subscriptions: impl Fn(&T) -> Sub<E> + 'static,
+) -> App<E>
+where
+    T: 'static,
+    E: 'static,
+    V: View<Web<E>> + 'static,
+    V::State: 'static,
+    C: Into<Command<E>>,
+{
+    app::start(Web::new(), state, update, f, subscriptions, true)
 }
@@ -0,0 +1,91 @@
+use super::Web;
+use crate::Modify;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+/// Reference to the element of an [`Html`](super::Html) view.
+///
+/// A `NodeRef` is set when the view is built and cleared when it's removed,
+/// so clones of it can be moved into event handlers and commands to access the element.
+///
+/// ```ignore
+/// let input = NodeRef::new();
+///
+/// (
+///     Html::input().node_ref(&input),
+///     Html::button()
+///         .on("click", move |_| {
+///             if let Some(input) = input.cast::<HtmlElement>() {
+///                 input.focus().unwrap();
+///             }
+///             Message::Focus
+///         })
+///         .view("Focus"),
+/// )
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NodeRef {
+    element: Rc<RefCell<Option<Element>>>,
+}
+
+impl NodeRef {
+    /// Create an empty reference.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The referenced element, or `None` if it isn't built.
+    pub fn get(&self) -> Option<Element> {
+        self.element.borrow().clone()
+    }
+
+    /// The referenced element as `T`, or `None` if it isn't built or isn't a `T`.
+    pub fn cast<T: JsCast>(&self) -> Option<T> {
+        self.get().and_then(|element| element.dyn_into().ok())
+    }
+
+    fn set(&self, element: &Element) {
+        *self.element.borrow_mut() = Some(element.clone());
+    }
+
+    /// Clear this reference if it still points to `element`.
+    fn clear(&self, element: &Element) {
+        let mut current = self.element.borrow_mut();
+        if current.as_ref() == Some(element) {
+            *current = None;
+        }
+    }
+}
+
+/// State for the [`NodeRef`] modifier, which clears the reference when it's dropped.
+pub struct NodeRefState {
+    node_ref: NodeRef,
+    element: Element,
+}
+
+impl Drop for NodeRefState {
+    fn drop(&mut self) {
+        self.node_ref.clear(&self.element);
+    }
+}
+
+impl<E> Modify<Web<E>, Element> for NodeRef {
+    type State = NodeRefState;
+
+    fn build(self, _cx: &mut Web<E>, elem: &mut Element) -> Self::State {
+        self.set(elem);
+        NodeRefState {
+            node_ref: self,
+            element: elem.clone(),
+        }
+    }
+
+    fn rebuild(self, _cx: &mut Web<E>, elem: &mut Element, state: &mut Self::State) {
+        if !Rc::ptr_eq(&self.element, &state.node_ref.element) {
+            state.node_ref.clear(&state.element);
+            self.set(elem);
+            state.node_ref = self;
+        }
+    }
+}
@@ -1,72 +1,79 @@
-use std::borrow::Cow;
-
-use super::Web;
-use crate::Modify;
+use super::{delegate::Registration, dispatch, UpdateFn, Web};
+use crate::{
+    html::{Handler, On},
+    Modify,
+};
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
 use wasm_bindgen::{prelude::Closure, JsCast};
 use web_sys::{Element, Event};
 
-/// Add an event listener to an element.
-///
-/// The event type `name` will listen for events using the `handler` function and
-/// emit the returned messages.
-pub fn on<F, E>(name: impl Into<Cow<'static, str>>, handler: F) -> On<F>
-where
-    F: FnMut(Event) -> E + 'static,
-    E: 'static,
-{
-    On {
-        name: name.into(),
-        handler,
-    }
+/// State for the [`On`] modifier.
+pub struct OnState<F, E> {
+    name: Cow<'static, str>,
+    handler: Rc<RefCell<(F, UpdateFn<E>)>>,
+    listener: Listener,
 }
 
-/// Modifier for the [`on`] function.
-pub struct On<F> {
-    /// Event name
-    name: Cow<'static, str>,
+enum Listener {
+    /// Listener added to the element.
+    Direct(Closure<dyn FnMut(Event)>),
 
-    /// Function to handle an event and return a message.
-    handler: F,
+    /// Handler registered with the [`Delegation`](super::Delegation) of an ancestor.
+    Delegated(Registration),
 }
 
 impl<F, E> Modify<Web<E>, Element> for On<F>
 where
-    F: FnMut(Event) -> E + 'static,
+    F: Handler<E> + 'static,
     E: 'static,
 {
-    type State = (Cow<'static, str>, Closure<dyn FnMut(Event)>);
+    type State = OnState<F, E>;
 
-    fn build(mut self, cx: &mut Web<E>, elem: &mut Element) -> Self::State {
-        let update_cell = cx.update.clone();
-        let closure: Closure<dyn FnMut(Event)> = Closure::new(move |event| {
-            let msg = (self.handler)(event);
+    fn build(self, cx: &mut Web<E>, elem: &mut Element) -> Self::State {
+        let handler = Rc::new(RefCell::new((self.handler, cx.update.clone())));
 
-            let mut update = update_cell.borrow_mut();
-            let update_fn = update.as_mut().unwrap();
-            update_fn(msg);
-        });
+        // The listener calls the latest handler, so it's only added once per element
+        let listener_handler = handler.clone();
+        let handle = move |event| {
+            let msg = listener_handler.borrow_mut().0.handle(event);
+            if let Some(msg) = msg {
+                let update = listener_handler.borrow().1.clone();
+                dispatch(&update, msg);
+            }
+        };
 
-        elem.add_event_listener_with_callback(&self.name, closure.as_ref().unchecked_ref())
-            .unwrap();
+        let listener = if let Some(delegation) = &cx.delegation {
+            Listener::Delegated(delegation.register(elem, self.name.clone(), Rc::new(handle)))
+        } else {
+            let closure: Closure<dyn FnMut(Event)> = Closure::new(handle);
+            elem.add_event_listener_with_callback(&self.name, closure.as_ref().unchecked_ref())
+                .unwrap();
+            Listener::Direct(closure)
+        };
 
-        (self.name, closure)
+        OnState {
+            name: self.name,
+            handler,
+            listener,
+        }
     }
 
-    fn rebuild(mut self, cx: &mut Web<E>, elem: &mut Element, state: &mut Self::State) {
-        let update_cell = cx.update.clone();
-        let closure: Closure<dyn FnMut(Event)> = Closure::new(move |event| {
-            let msg = (self.handler)(event);
-
-            let mut update = update_cell.borrow_mut();
-            let update_fn = update.as_mut().unwrap();
-            update_fn(msg);
-        });
+    fn rebuild(self, cx: &mut Web<E>, elem: &mut Element, state: &mut Self::State) {
+        *state.handler.borrow_mut() = (self.handler, cx.update.clone());
 
-        elem.remove_event_listener_with_callback(&state.0, state.1.as_ref().unchecked_ref())
-            .unwrap();
-        elem.add_event_listener_with_callback(&self.name, closure.as_ref().unchecked_ref())
-            .unwrap();
+        if self.name != state.name {
+            match &state.listener {
+                Listener::Direct(closure) => {
+                    let callback = closure.as_ref().unchecked_ref();
+                    elem.remove_event_listener_with_callback(&state.name, callback)
+                        .unwrap();
+                    elem.add_event_listener_with_callback(&self.name, callback)
+                        .unwrap();
+                }
+                Listener::Delegated(registration) => registration.rename(self.name.clone()),
+            }
 
-        *state = (self.name, closure);
+            state.name = self.name;
+        }
     }
 }
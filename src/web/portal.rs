@@ -0,0 +1,93 @@
+use super::Web;
+use crate::View;
+use std::mem;
+use web_sys::{Comment, Element, Node};
+
+/// Portal view that renders its child into a different parent element.
+///
+/// This lets views like modals and tooltips be declared deep inside the view tree
+/// while their nodes are appended to `target`, such as `document.body`.
+/// The portal itself takes up no nodes in its parent.
+pub fn portal<V>(target: Element, child: V) -> Portal<V> {
+    Portal { target, child }
+}
+
+/// View for the [`portal`] function.
+pub struct Portal<V> {
+    target: Element,
+    child: V,
+}
+
+/// State for the [`Portal`] view.
+pub struct PortalState<S> {
+    target: Element,
+    anchor: Comment,
+    child: S,
+}
+
+impl<E, V> View<Web<E>> for Portal<V>
+where
+    V: View<Web<E>>,
+{
+    type State = PortalState<V::State>;
+
+    fn build(self, cx: &mut Web<E>) -> Self::State {
+        // Mark where the child's nodes start, in case the target has other children
+        let anchor = cx.document.create_comment("portal");
+        self.target.append_child(&anchor).unwrap();
+
+        let child = with_target(cx, &self.target, &anchor, |cx| self.child.build(cx));
+        PortalState {
+            target: self.target,
+            anchor,
+            child,
+        }
+    }
+
+    fn rebuild(self, cx: &mut Web<E>, state: &mut Self::State) {
+        if self.target != state.target {
+            Self::remove(cx, state);
+            *state = self.build(cx);
+            return;
+        }
+
+        with_target(cx, &state.target, &state.anchor, |cx| {
+            self.child.rebuild(cx, &mut state.child)
+        });
+    }
+
+    fn remove(cx: &mut Web<E>, state: &mut Self::State) {
+        with_target(cx, &state.target, &state.anchor, |cx| {
+            V::remove(cx, &mut state.child)
+        });
+        state.anchor.remove();
+    }
+}
+
+/// Run `f` with `target` as the parent, starting after `anchor`.
+fn with_target<E, R>(
+    cx: &mut Web<E>,
+    target: &Element,
+    anchor: &Node,
+    f: impl FnOnce(&mut Web<E>) -> R,
+) -> R {
+    let nodes = target.child_nodes();
+    let start = (0..nodes.length())
+        .find(|idx| nodes.get(*idx).as_ref() == Some(anchor))
+        .unwrap()
+        + 1;
+
+    // Portals aren't part of server-rendered markup, so their children are always created
+    let is_hydrating = mem::replace(&mut cx.is_hydrating, false);
+
+    // Events in the target don't bubble to the root of a delegation
+    let delegation = cx.delegation.take();
+
+    cx.stack.push((target.clone(), start as _));
+    let output = f(cx);
+    cx.stack.pop();
+    cx.is_hydrating = is_hydrating;
+    cx.delegation = delegation;
+
+    output
+}
@@ -0,0 +1,38 @@
+use super::Web;
+use crate::Modify;
+use std::borrow::Cow;
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+
+/// Set a property of an element's DOM object, like `checked` or `selectedIndex`.
+///
+/// Unlike attributes, properties reflect the live state of an element,
+/// so the property is set again on rebuild whenever its current value differs from `value`.
+pub fn prop(name: impl Into<Cow<'static, str>>, value: impl Into<JsValue>) -> Prop {
+    Prop {
+        name: name.into(),
+        value: value.into(),
+    }
+}
+
+/// Modifier for the [`prop`] function.
+pub struct Prop {
+    name: Cow<'static, str>,
+    value: JsValue,
+}
+
+impl<E> Modify<Web<E>, Element> for Prop {
+    type State = ();
+
+    fn build(self, _cx: &mut Web<E>, elem: &mut Element) -> Self::State {
+        js_sys::Reflect::set(elem, &JsValue::from_str(&self.name), &self.value).unwrap();
+    }
+
+    fn rebuild(self, _cx: &mut Web<E>, elem: &mut Element, _state: &mut Self::State) {
+        // Compare with the live value, since the user may have changed it since the last build
+        let name = JsValue::from_str(&self.name);
+        if js_sys::Reflect::get(elem, &name).unwrap() != self.value {
+            js_sys::Reflect::set(elem, &name, &self.value).unwrap();
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::Sub;
+use std::{borrow::Cow, cell::RefCell, rc::Rc, time::Duration};
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{Event, KeyboardEvent};
+
+/// Subscription to a timer that fires every `duration`.
+pub fn interval<E, F>(duration: Duration, f: F) -> Sub<E>
+where
+    F: Fn() -> E + 'static,
+    E: 'static,
+{
+    Sub::new(duration, move |send| {
+        let window = web_sys::window().unwrap();
+        let closure: Closure<dyn FnMut()> = Closure::new(move || send(f()));
+        let handle = window
+            .set_interval_with_callback_and_timeout_and_arguments_0(
+                closure.as_ref().unchecked_ref(),
+                duration.as_millis() as _,
+            )
+            .unwrap();
+
+        move || {
+            window.clear_interval_with_handle(handle);
+            drop(closure);
+        }
+    })
+}
+
+/// Subscription to animation frames.
+///
+/// The handler is called with the frame's timestamp in milliseconds.
+pub fn animation_frame<E, F>(f: F) -> Sub<E>
+where
+    F: Fn(f64) -> E + 'static,
+    E: 'static,
+{
+    Sub::new((), move |send| {
+        let window = web_sys::window().unwrap();
+        let handle = Rc::new(RefCell::new(None));
+        let closure = Rc::new(RefCell::new(None::<Closure<dyn FnMut(f64)>>));
+
+        // Request the next frame from inside each callback
+        let frame_window = window.clone();
+        let frame_handle = handle.clone();
+        let frame_closure = Rc::downgrade(&closure);
+        *closure.borrow_mut() = Some(Closure::new(move |timestamp| {
+            send(f(timestamp));
+
+            if let Some(closure) = frame_closure.upgrade() {
+                let id = frame_window
+                    .request_animation_frame(
+                        closure.borrow().as_ref().unwrap().as_ref().unchecked_ref(),
+                    )
+                    .unwrap();
+                *frame_handle.borrow_mut() = Some(id);
+            }
+        }));
+
+        let id = window
+            .request_animation_frame(closure.borrow().as_ref().unwrap().as_ref().unchecked_ref())
+            .unwrap();
+        *handle.borrow_mut() = Some(id);
+
+        move || {
+            if let Some(id) = handle.borrow_mut().take() {
+                window.cancel_animation_frame(id).unwrap();
+            }
+            closure.borrow_mut().take();
+        }
+    })
+}
+
+/// Subscription to events of type `name` on the window.
+pub fn on_window<E, F>(name: impl Into<Cow<'static, str>>, f: F) -> Sub<E>
+where
+    F: Fn(Event) -> E + 'static,
+    E: 'static,
+{
+    let name = name.into();
+    Sub::new(name.clone(), move |send| {
+        let window = web_sys::window().unwrap();
+        let closure: Closure<dyn FnMut(Event)> = Closure::new(move |event| send(f(event)));
+        window
+            .add_event_listener_with_callback(&name, closure.as_ref().unchecked_ref())
+            .unwrap();
+
+        move || {
+            window
+                .remove_event_listener_with_callback(&name, closure.as_ref().unchecked_ref())
+                .unwrap();
+        }
+    })
+}
+
+/// Subscription to window resizes.
+///
+/// The handler is called with the new inner width and height of the window.
+pub fn on_resize<E, F>(f: F) -> Sub<E>
+where
+    F: Fn(f64, f64) -> E + 'static,
+    E: 'static,
+{
+    on_window("resize", move |_| {
+        let window = web_sys::window().unwrap();
+        let width = window.inner_width().unwrap().as_f64().unwrap();
+        let height = window.inner_height().unwrap().as_f64().unwrap();
+        f(width, height)
+    })
+}
+
+/// Subscription to key presses anywhere in the window.
+pub fn on_key_down<E, F>(f: F) -> Sub<E>
+where
+    F: Fn(KeyboardEvent) -> E + 'static,
+    E: 'static,
+{
+    on_window("keydown", move |event| f(event.unchecked_into()))
+}